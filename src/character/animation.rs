@@ -1,4 +1,5 @@
 use bevy::{platform::collections::HashMap, prelude::*};
+use serde::Deserialize;
 
 use crate::{
     animation::{AnimationData, AnimationIndices, AnimationTimer},
@@ -12,11 +13,21 @@ pub(super) fn plugin(app: &mut App) {
             .chain()
             .in_set(InGameSystems::Vfx),
     )
-    .insert_resource(DefaultAnimationConfig::default());
+    .insert_resource(CharacterAnimationConfigs::default());
+}
+
+/// Which sprite sheet layout a character is animated with
+#[derive(Component, Deserialize, Default, PartialEq, Debug, Copy, Clone)]
+pub(super) enum AnimationSet {
+    /// 13x21 character sheet shared by the player, NPCs and humanoid enemies
+    #[default]
+    Humanoid,
+    /// 4x4 sheet, one row per facing direction
+    Bat,
 }
 
 #[derive(Component, Default, PartialEq, Debug, Hash, Eq, Copy, Clone)]
-#[require(FacingDirection, SimpleMotion)]
+#[require(FacingDirection, SimpleMotion, AnimationSet)]
 pub(super) enum CharacterAnimationState {
     #[default]
     Idle,
@@ -40,7 +51,7 @@ fn update_animation_state(
     for (motion, attack_state, health, mut animation_state, mut facing_direction) in
         &mut character_query
     {
-        if health.is_none() || health.is_some_and(|h| h.hp <= 0.0) {
+        if health.is_none_or(|h| h.hp <= 0.0) {
             animation_state.set_if_neq(CharacterAnimationState::Dying);
             continue;
         }
@@ -65,7 +76,7 @@ fn update_animation_state(
 }
 
 fn cycle_character_animation(
-    animation_config: Res<DefaultAnimationConfig>,
+    animation_configs: Res<CharacterAnimationConfigs>,
     mut query: Query<
         (
             &mut AnimationIndices,
@@ -73,11 +84,13 @@ fn cycle_character_animation(
             &mut Sprite,
             &CharacterAnimationState,
             &FacingDirection,
+            &AnimationSet,
         ),
         Or<(Changed<CharacterAnimationState>, Changed<FacingDirection>)>,
     >,
 ) {
-    for (mut indices, mut timer, mut sprite, state, direction) in &mut query {
+    for (mut indices, mut timer, mut sprite, state, direction, animation_set) in &mut query {
+        let animation_config = animation_configs.get(*animation_set);
         *indices = animation_config.get_indices(*state, *direction);
         *timer = AnimationTimer(animation_config.get_timer(*state, *direction));
         if let Some(atlas) = &mut sprite.texture_atlas {
@@ -87,13 +100,36 @@ fn cycle_character_animation(
 }
 
 #[derive(Resource)]
-struct DefaultAnimationConfig {
+struct CharacterAnimationConfigs {
+    humanoid: CharacterAnimationConfig,
+    bat: CharacterAnimationConfig,
+}
+
+impl Default for CharacterAnimationConfigs {
+    fn default() -> Self {
+        Self {
+            humanoid: CharacterAnimationConfig::humanoid(),
+            bat: CharacterAnimationConfig::bat(),
+        }
+    }
+}
+
+impl CharacterAnimationConfigs {
+    fn get(&self, animation_set: AnimationSet) -> &CharacterAnimationConfig {
+        match animation_set {
+            AnimationSet::Humanoid => &self.humanoid,
+            AnimationSet::Bat => &self.bat,
+        }
+    }
+}
+
+struct CharacterAnimationConfig {
     columns: usize,
     animations: HashMap<(CharacterAnimationState, FacingDirection), AnimationData>,
 }
 
-impl Default for DefaultAnimationConfig {
-    fn default() -> Self {
+impl CharacterAnimationConfig {
+    fn humanoid() -> Self {
         use CharacterAnimationState::{Attacking, Dying, Idle, Moving};
         use FacingDirection::{Down, Left, Right, Up};
         let data = [
@@ -114,18 +150,53 @@ impl Default for DefaultAnimationConfig {
             (Attacking, Down, (18, 9, 0.1)),
             (Attacking, Right, (19, 9, 0.1)),
         ];
+        Self::from_data(13, data)
+    }
+
+    /// Bats never stop flapping, so every state reuses the direction row at a different speed
+    fn bat() -> Self {
+        use CharacterAnimationState::{Attacking, Dying, Idle, Moving};
+        use FacingDirection::{Down, Left, Right, Up};
+        let data = [
+            (Idle, Down, (0, 4, 0.15)),
+            (Idle, Left, (1, 4, 0.15)),
+            (Idle, Right, (2, 4, 0.15)),
+            (Idle, Up, (3, 4, 0.15)),
+            (Moving, Down, (0, 4, 0.08)),
+            (Moving, Left, (1, 4, 0.08)),
+            (Moving, Right, (2, 4, 0.08)),
+            (Moving, Up, (3, 4, 0.08)),
+            (Attacking, Down, (0, 4, 0.05)),
+            (Attacking, Left, (1, 4, 0.05)),
+            (Attacking, Right, (2, 4, 0.05)),
+            (Attacking, Up, (3, 4, 0.05)),
+            (Dying, Down, (0, 4, 0.4)),
+            (Dying, Left, (1, 4, 0.4)),
+            (Dying, Right, (2, 4, 0.4)),
+            (Dying, Up, (3, 4, 0.4)),
+        ];
+        Self::from_data(4, data)
+    }
+
+    fn from_data(
+        columns: usize,
+        data: impl IntoIterator<
+            Item = (
+                CharacterAnimationState,
+                FacingDirection,
+                (usize, usize, f32),
+            ),
+        >,
+    ) -> Self {
         let animations = data
             .into_iter()
             .map(|(state, dir, data)| ((state, dir), AnimationData::from(data)))
             .collect::<HashMap<_, _>>();
         Self {
-            columns: 13,
+            columns,
             animations,
         }
     }
-}
-
-impl DefaultAnimationConfig {
     fn get_animation(
         &self,
         state: CharacterAnimationState,
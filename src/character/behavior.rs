@@ -5,7 +5,11 @@ use std::ops::Range;
 use bevy::prelude::*;
 
 use crate::{
-    character::vision::{TargetInfo, Targeting},
+    character::{
+        Character,
+        steering::Stuck,
+        vision::{TargetInfo, Targeting},
+    },
     prelude::*,
 };
 
@@ -18,11 +22,19 @@ pub(super) fn plugin(app: &mut App) {
             while_wandering,
             while_retreating,
             while_keeping_distance_and_firing,
+            while_investigating,
+            while_swooping,
+            while_fleeing,
+            while_supporting_allies,
+            while_hunting,
         )
             .in_set(InGameSystems::Simulation),
     )
     .add_observer(on_idle_start)
+    .add_observer(on_flee_start)
+    .add_observer(on_swoop_start)
     .add_observer(on_wander_start)
+    .add_observer(on_support_allies_end)
     .add_observer(on_attempt_melee);
 }
 
@@ -63,12 +75,17 @@ pub fn while_idling(
     mut commands: Commands,
     time: Res<Time>,
     mut idle_query: Query<(&BehaveCtx, &mut Idle)>,
-    target_query: Query<&Targeting>,
+    target_query: Query<(Has<Targeting>, Has<HeardNoise>)>,
 ) {
     idle_query.iter_mut().for_each(|(ctx, mut idle)| {
-        if target_query.get(ctx.target_entity()).is_ok() {
+        let (has_target, heard_noise) = target_query.get(ctx.target_entity()).unwrap_or_default();
+
+        if has_target {
             info!("{} Got target while idling", ctx.target_entity());
             commands.trigger(ctx.failure());
+        } else if heard_noise {
+            debug!("{} Heard a noise while idling", ctx.target_entity());
+            commands.trigger(ctx.failure());
         } else if idle.timer.tick(time.delta()).just_finished() {
             commands.trigger(ctx.success());
         }
@@ -144,12 +161,32 @@ pub fn while_wandering(
     time: Res<Time>,
     mut commands: Commands,
     mut wander_query: Query<(&BehaveCtx, &mut Wander)>,
-    target_query: Query<&Targeting>,
+    mut target_query: Query<(
+        &mut SimpleMotion,
+        Has<Targeting>,
+        Has<HeardNoise>,
+        Has<Stuck>,
+    )>,
 ) {
     wander_query.iter_mut().for_each(|(ctx, mut wander)| {
-        if target_query.get(ctx.target_entity()).is_ok() {
+        let Ok((mut motion, has_target, heard_noise, is_stuck)) =
+            target_query.get_mut(ctx.target_entity())
+        else {
+            return;
+        };
+
+        if is_stuck {
+            // Wandering has nowhere in particular to be, just try another way
+            commands.entity(ctx.target_entity()).remove::<Stuck>();
+            motion.start_moving(random_direction());
+        }
+
+        if has_target {
             info!("{} Got target while wandering", ctx.target_entity());
             commands.trigger(ctx.failure());
+        } else if heard_noise {
+            debug!("{} Heard a noise while wandering", ctx.target_entity());
+            commands.trigger(ctx.failure());
         } else if wander.timer.tick(time.delta()).just_finished() {
             commands.trigger(ctx.success());
         }
@@ -163,13 +200,21 @@ pub struct Retreat;
 pub fn while_retreating(
     mut commands: Commands,
     mut retreat_query: Query<&BehaveCtx, With<Retreat>>,
-    mut target_query: Query<(&mut SimpleMotion, &Transform, &Anchor, Has<Targeting>)>,
+    mut target_query: Query<(
+        &mut SimpleMotion,
+        &Transform,
+        &Anchor,
+        Has<Targeting>,
+        Has<Stuck>,
+    )>,
 ) -> Result {
     retreat_query.iter_mut().try_for_each(|ctx| {
-        let (mut motion, transform, anchor, has_target) =
+        let (mut motion, transform, anchor, has_target, is_stuck) =
             target_query.get_mut(ctx.target_entity())?;
         if has_target {
             commands.trigger(ctx.failure());
+        } else if is_stuck {
+            give_up_when_stuck(&mut commands, ctx);
             // within half a tile, we can stop retreating
         } else if anchor.distance_from(transform) < 16.0 {
             commands.trigger(ctx.success());
@@ -181,6 +226,49 @@ pub fn while_retreating(
     })
 }
 
+/// When a character hears a noise, it walks over to where the noise came from to take a look
+#[derive(Component, Clone)]
+pub struct Investigate;
+
+pub fn while_investigating(
+    mut commands: Commands,
+    mut investigate_query: Query<&BehaveCtx, With<Investigate>>,
+    mut target_query: Query<(
+        &mut SimpleMotion,
+        &Transform,
+        Option<&HeardNoise>,
+        Has<Targeting>,
+        Has<Stuck>,
+    )>,
+) -> Result {
+    investigate_query.iter_mut().try_for_each(|ctx| {
+        let (mut motion, transform, heard_noise, has_target, is_stuck) =
+            target_query.get_mut(ctx.target_entity())?;
+
+        if has_target {
+            commands.trigger(ctx.failure());
+        } else if is_stuck {
+            // Can't get there from here, not worth the trouble
+            commands.entity(ctx.target_entity()).remove::<HeardNoise>();
+            give_up_when_stuck(&mut commands, ctx);
+        } else if let Some(heard_noise) = heard_noise {
+            // within half a tile, we've seen all there is to see
+            if heard_noise.position.distance(transform.translation.xy()) < 16.0 {
+                commands.entity(ctx.target_entity()).remove::<HeardNoise>();
+                commands.trigger(ctx.success());
+            } else {
+                let direction =
+                    (heard_noise.position - transform.translation.xy()).normalize_or_zero();
+                motion.start_moving(direction);
+            }
+        } else {
+            // Nothing to investigate (or we got bored of it)
+            commands.trigger(ctx.failure());
+        }
+        Ok(())
+    })
+}
+
 /// When a character has a target, it moves towards them. The chase!!
 #[derive(Component, Clone)]
 pub struct Chase;
@@ -188,12 +276,15 @@ pub struct Chase;
 pub fn while_chasing(
     mut commands: Commands,
     mut chase_query: Query<&BehaveCtx, With<Chase>>,
-    mut target_query: Query<(&mut SimpleMotion, &TargetInfo, Has<Targeting>)>,
+    mut target_query: Query<(&mut SimpleMotion, &TargetInfo, Has<Targeting>, Has<Stuck>)>,
 ) -> Result {
     chase_query.iter_mut().try_for_each(|ctx| {
-        let (mut motion, target_info, has_target) = target_query.get_mut(ctx.target_entity())?;
+        let (mut motion, target_info, has_target, is_stuck) =
+            target_query.get_mut(ctx.target_entity())?;
 
-        if has_target {
+        if is_stuck {
+            give_up_when_stuck(&mut commands, ctx);
+        } else if has_target {
             motion.start_moving(target_info.direction);
 
             if target_info.distance < 64.0 {
@@ -207,6 +298,62 @@ pub fn while_chasing(
     })
 }
 
+/// Dives at the target in a (slightly wobbly) straight line, overshooting them before pulling away
+#[derive(Component, Clone)]
+pub struct Swoop {
+    duration: Timer,
+}
+
+impl Default for Swoop {
+    fn default() -> Self {
+        Self {
+            duration: Timer::from_seconds(0.5, TimerMode::Once),
+        }
+    }
+}
+
+pub fn on_swoop_start(
+    swoop: On<Add, Swoop>,
+    mut commands: Commands,
+    swoop_query: Query<&BehaveCtx, With<Swoop>>,
+    mut target_query: Query<(&mut SimpleMotion, &TargetInfo, Has<Targeting>)>,
+) -> Result {
+    let ctx = swoop_query.get(swoop.entity)?;
+    let (mut motion, target_info, has_target) = target_query.get_mut(ctx.target_entity())?;
+
+    if has_target {
+        let wobble = rng().random_range(-0.35..0.35);
+        motion.start_moving(Vec2::from_angle(wobble).rotate(target_info.direction));
+    } else {
+        commands.trigger(ctx.failure());
+    }
+    Ok(())
+}
+
+pub fn while_swooping(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut swoop_query: Query<(&BehaveCtx, &mut Swoop)>,
+    stuck_query: Query<(), With<Stuck>>,
+) {
+    swoop_query.iter_mut().for_each(|(ctx, mut swoop)| {
+        // Direction was locked in when the swoop began, we just keep going until it's over
+        if swoop.duration.tick(time.delta()).just_finished() {
+            commands.trigger(ctx.success());
+        } else if stuck_query.contains(ctx.target_entity()) {
+            // Dove into a wall, the swoop is over early
+            commands.entity(ctx.target_entity()).remove::<Stuck>();
+            commands.trigger(ctx.success());
+        }
+    });
+}
+
+/// Fails the behaviour and clears `Stuck`, so the next behaviour gets a fresh chance to move
+fn give_up_when_stuck(commands: &mut Commands, ctx: &BehaveCtx) {
+    commands.entity(ctx.target_entity()).remove::<Stuck>();
+    commands.trigger(ctx.failure());
+}
+
 fn random_direction() -> Vec2 {
     let mut rng = rng();
     let angle = rng.random_range(0.0..std::f32::consts::TAU);
@@ -251,14 +398,18 @@ pub fn while_keeping_distance_and_firing(
         &TargetInfo,
         Option<&Mainhand>,
         Has<Targeting>,
+        Has<Stuck>,
     )>,
 ) -> Result {
     behave_query.iter_mut().try_for_each(|ctx| {
-        let (mut motion, target_info, mainhand, has_target) =
+        let (mut motion, target_info, mainhand, has_target, is_stuck) =
             target_query.get_mut(ctx.target_entity())?;
 
         if !has_target {
             commands.trigger(ctx.failure());
+        } else if is_stuck {
+            // Backed into a corner, let the tree decide what to do next
+            give_up_when_stuck(&mut commands, ctx);
         } else if let Some(mainhand) = mainhand {
             commands.trigger(AIUseEquipment {
                 entity: mainhand.get(),
@@ -277,3 +428,243 @@ pub fn while_keeping_distance_and_firing(
         Ok(())
     })
 }
+
+/// Runs away from the target once health drops below `health_threshold`, heading toward allies if there are any
+/// nearby. Drinks a health potion on the way out if the character carries one
+#[derive(Component, Clone)]
+pub struct Flee {
+    /// Fraction of max health (0.0 to 1.0) the character starts fleeing below
+    health_threshold: f32,
+}
+
+impl Flee {
+    /// How far away allies can be and still be worth running toward
+    const ALLY_RANGE: f32 = 300.0;
+
+    pub fn new(health_threshold: f32) -> Self {
+        Self { health_threshold }
+    }
+}
+
+pub fn on_flee_start(
+    flee: On<Add, Flee>,
+    mut commands: Commands,
+    flee_query: Query<(&BehaveCtx, &Flee)>,
+    target_query: Query<(&Health, Option<&Items>, Has<Targeting>)>,
+    consumable_query: Query<&Consumable>,
+) -> Result {
+    let (ctx, flee) = flee_query.get(flee.entity)?;
+    let (health, items, has_target) = target_query.get(ctx.target_entity())?;
+
+    if !has_target || health.fraction() >= flee.health_threshold {
+        commands.trigger(ctx.failure());
+        return Ok(());
+    }
+
+    let potion = items.and_then(|items| {
+        items.iter().find(|item| {
+            consumable_query
+                .get(*item)
+                .is_ok_and(|c| matches!(c.effect, ConsumableType::Heal(_)))
+        })
+    });
+
+    if let Some(potion) = potion {
+        commands.trigger(Consume {
+            entity: ctx.target_entity(),
+            item_entity: potion,
+        });
+    }
+    Ok(())
+}
+
+pub fn while_fleeing(
+    mut commands: Commands,
+    flee_query: Query<(&BehaveCtx, &Flee)>,
+    mut target_query: Query<(
+        &mut SimpleMotion,
+        &Transform,
+        &TargetInfo,
+        &Health,
+        &Faction,
+        Has<Targeting>,
+        Has<Stuck>,
+    )>,
+    ally_query: Query<(Entity, &Transform, &Faction, &Health), With<Character>>,
+) -> Result {
+    flee_query.iter().try_for_each(|(ctx, flee)| {
+        let (mut motion, transform, target_info, health, faction, has_target, is_stuck) =
+            target_query.get_mut(ctx.target_entity())?;
+
+        if !has_target || health.fraction() >= flee.health_threshold {
+            // Got away (or healed up), back to business
+            commands.trigger(ctx.success());
+        } else if is_stuck {
+            // Cornered, time to fight
+            give_up_when_stuck(&mut commands, ctx);
+        } else {
+            let position = transform.translation.xy();
+            let nearest_ally = ally_query
+                .iter()
+                .filter(|(entity, _, ally_faction, ally_health)| {
+                    *entity != ctx.target_entity()
+                        && *ally_faction == faction
+                        && !ally_health.is_dead()
+                })
+                .map(|(_, ally_transform, _, _)| ally_transform.translation.xy() - position)
+                .filter(|offset| offset.length() < Flee::ALLY_RANGE)
+                .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()));
+
+            let ally_direction = nearest_ally.map_or(Vec2::ZERO, Vec2::normalize_or_zero);
+            let direction = (ally_direction - target_info.direction).normalize_or_zero();
+            motion.start_moving(direction);
+        }
+        Ok(())
+    })
+}
+
+/// Keeps injured allies alive, casting the offhand (ex. `tome_of_mending`) on whoever is lowest on health.
+/// With nobody to heal it hangs back and fires its mainhand at the target instead.
+/// Fails once there is nobody to heal and nothing to fight
+#[derive(Component, Clone)]
+pub struct SupportAllies;
+
+impl SupportAllies {
+    /// How far the support looks for allies that need help
+    const SCAN_RANGE: f32 = 400.0;
+    /// How close the support needs to be to cast on an ally
+    const CAST_RANGE: f32 = 150.0;
+    /// Allies above this fraction of max health don't need healing yet
+    const INJURED_THRESHOLD: f32 = 0.9;
+    /// Distance the support tries to keep from its target
+    const SAFE_DISTANCE: f32 = 250.0;
+}
+
+pub fn while_supporting_allies(
+    mut commands: Commands,
+    support_query: Query<&BehaveCtx, With<SupportAllies>>,
+    mut target_query: Query<(
+        &mut SimpleMotion,
+        &Transform,
+        &Faction,
+        &TargetInfo,
+        Option<&Mainhand>,
+        Option<&Offhand>,
+        Has<Targeting>,
+        Has<Stuck>,
+    )>,
+    ally_query: Query<(Entity, &Transform, &Faction, &Health), With<Character>>,
+) -> Result {
+    support_query.iter().try_for_each(|ctx| {
+        let (mut motion, transform, faction, target_info, mainhand, offhand, has_target, is_stuck) =
+            target_query.get_mut(ctx.target_entity())?;
+
+        if is_stuck {
+            give_up_when_stuck(&mut commands, ctx);
+            return Ok(());
+        }
+
+        let position = transform.translation.xy();
+        let most_injured = ally_query
+            .iter()
+            .filter(|(_, ally_transform, ally_faction, health)| {
+                *ally_faction == faction
+                    && !health.is_dead()
+                    && health.fraction() < SupportAllies::INJURED_THRESHOLD
+                    && ally_transform.translation.xy().distance(position)
+                        < SupportAllies::SCAN_RANGE
+            })
+            .min_by(|(.., a), (.., b)| a.fraction().total_cmp(&b.fraction()));
+
+        if let (Some((ally, ally_transform, ..)), Some(offhand)) = (most_injured, offhand) {
+            let to_ally = ally_transform.translation.xy() - position;
+
+            if to_ally.length() > SupportAllies::CAST_RANGE {
+                motion.start_moving(to_ally.normalize_or_zero());
+            } else {
+                motion.stop_moving();
+                commands
+                    .entity(ctx.target_entity())
+                    .insert(SpellTarget(ally));
+                commands.trigger(AIUseEquipment {
+                    entity: offhand.get(),
+                });
+            }
+        } else if has_target {
+            if target_info.distance < SupportAllies::SAFE_DISTANCE {
+                motion.start_moving(-target_info.direction);
+            } else {
+                motion.stop_moving();
+            }
+
+            if let Some(mainhand) = mainhand {
+                commands.trigger(AIUseEquipment {
+                    entity: mainhand.get(),
+                });
+            }
+        } else {
+            commands.trigger(ctx.failure());
+        }
+        Ok(())
+    })
+}
+
+/// Whoever the support was healing is forgotten, so casts from other behaviours go back to the holder
+pub fn on_support_allies_end(
+    support: On<Remove, SupportAllies>,
+    mut commands: Commands,
+    support_query: Query<&BehaveCtx>,
+) -> Result {
+    let ctx = support_query.get(support.entity)?;
+    commands
+        .entity(ctx.target_entity())
+        .try_remove::<SpellTarget>();
+    Ok(())
+}
+
+/// Relentlessly follows the player wherever they are, pathing around walls, and attacks once close enough.
+/// Never gives up
+#[derive(Component, Clone)]
+pub struct Hunt;
+
+impl Hunt {
+    const ATTACK_RANGE: f32 = 64.0;
+}
+
+pub fn while_hunting(
+    mut commands: Commands,
+    hunt_query: Query<&BehaveCtx, With<Hunt>>,
+    mut hunter_query: Query<(&mut SimpleMotion, &Transform, Option<&Mainhand>), Without<Player>>,
+    player_transform: Single<&Transform, With<Player>>,
+    hunt_path: Res<HuntPath>,
+    world_config: Res<WorldSpaceConfig>,
+    map_layout: Res<MapLayout>,
+) -> Result {
+    let player_position = player_transform.translation.xy();
+
+    hunt_query.iter().try_for_each(|ctx| {
+        let (mut motion, transform, mainhand) = hunter_query.get_mut(ctx.target_entity())?;
+        let position = transform.translation.xy();
+        let to_player = player_position - position;
+
+        if to_player.length() < Hunt::ATTACK_RANGE {
+            motion.start_moving(to_player.normalize_or_zero());
+
+            if let Some(mainhand) = mainhand {
+                commands.trigger(AIUseEquipment {
+                    entity: mainhand.get(),
+                });
+            }
+        } else {
+            // Straight at them if the path doesn't know the way (yet)
+            let direction = hunt_path
+                .direction_from(position, &world_config, &map_layout)
+                .unwrap_or_else(|| to_player.normalize_or_zero());
+            motion.start_moving(direction);
+        }
+
+        // Stuck doesn't matter, the path already goes around what we can see
+        commands.entity(ctx.target_entity()).remove::<Stuck>();
+        Ok(())
+    })
+}
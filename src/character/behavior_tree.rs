@@ -0,0 +1,201 @@
+use std::{collections::HashMap, io::BufReader};
+
+use bevy::{prelude::*, scene::ron::de::from_reader};
+use bevy_behave::prelude::*;
+use serde::Deserialize;
+
+use crate::character::behavior::{
+    AttemptMelee, Chase, Flee, Hunt, Idle, Investigate, KeepDistanceAndFire, Retreat,
+    SupportAllies, Swoop, Wander,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Startup, setup_behavior_trees);
+}
+
+/// Trees can reference each other, this keeps a bad config from recursing forever
+const MAX_TREE_DEPTH: usize = 32;
+
+/// Named behaviour trees loaded from `config/behaviors.ron`, enemies and NPCs reference them by name
+#[derive(Resource)]
+pub struct BehaviorTrees {
+    trees: HashMap<String, BehaviorNode>,
+}
+
+impl BehaviorTrees {
+    /// Builds a fresh tree, re-rolling any randomized parameters (ex. timer ranges)
+    pub fn build(&self, name: &str) -> Option<Tree<Behave>> {
+        let Some(node) = self.trees.get(name) else {
+            warn!("Unknown behaviour tree: {name}");
+            return None;
+        };
+
+        self.build_node(node, 0)
+    }
+
+    fn build_node(&self, node: &BehaviorNode, depth: usize) -> Option<Tree<Behave>> {
+        if depth > MAX_TREE_DEPTH {
+            error!("Behaviour tree is too deep, check for trees referencing themselves");
+            return None;
+        }
+
+        // Referenced trees are spliced in as a subtree
+        if let BehaviorNode::Tree(name) = node {
+            let Some(referenced) = self.trees.get(name) else {
+                warn!("Unknown behaviour tree: {name}");
+                return None;
+            };
+            return self.build_node(referenced, depth + 1);
+        }
+
+        let mut tree = Tree::new(node.to_behave()?);
+        for child in node.children() {
+            tree.root_mut()
+                .append_subtree(self.build_node(child, depth + 1)?);
+        }
+
+        Some(tree)
+    }
+}
+
+/// Serializable description of a behaviour tree node, maps to the leaf components in `behavior`
+#[derive(Deserialize, Debug, Clone)]
+pub enum BehaviorNode {
+    // Control nodes
+    Sequence(Vec<BehaviorNode>),
+    Fallback(Vec<BehaviorNode>),
+    /// Runs the second node as long as the first one succeeds
+    While(Box<BehaviorNode>, Box<BehaviorNode>),
+    Forever(Box<BehaviorNode>),
+    /// Another tree from the library, by name
+    Tree(String),
+
+    // Leaves
+    Idle {
+        timer_range: (f32, f32),
+    },
+    Wander {
+        timer_range: (f32, f32),
+    },
+    Retreat,
+    Investigate,
+    Chase,
+    KeepDistanceAndFire,
+    AttemptMelee,
+    Swoop,
+    SupportAllies,
+    Hunt,
+    /// Runs from the target below this fraction of max health
+    Flee {
+        health_threshold: f32,
+    },
+}
+
+impl BehaviorNode {
+    fn children(&self) -> Vec<&BehaviorNode> {
+        match self {
+            BehaviorNode::Sequence(children) | BehaviorNode::Fallback(children) => {
+                children.iter().collect()
+            }
+            BehaviorNode::While(condition, body) => vec![condition, body],
+            BehaviorNode::Forever(child) => vec![child],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Catches values that would only blow up once the tree is built, like an empty timer range
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            BehaviorNode::Idle { timer_range } | BehaviorNode::Wander { timer_range }
+                if (timer_range.0..timer_range.1).is_empty()
+                    || !timer_range.0.is_finite()
+                    || !timer_range.1.is_finite() =>
+            {
+                Err(format!(
+                    "timer_range {timer_range:?} must be finite with the first value less than the second"
+                ))
+            }
+            _ => self
+                .children()
+                .into_iter()
+                .try_for_each(BehaviorNode::validate),
+        }
+    }
+
+    /// `None` for `Tree`, which is resolved by `BehaviorTrees` instead
+    fn to_behave(&self) -> Option<Behave> {
+        let behave = match self {
+            BehaviorNode::Sequence(_) => Behave::Sequence,
+            BehaviorNode::Fallback(_) => Behave::Fallback,
+            BehaviorNode::While(..) => Behave::While,
+            BehaviorNode::Forever(_) => Behave::Forever,
+            BehaviorNode::Tree(_) => return None,
+            BehaviorNode::Idle { timer_range } => Behave::spawn_named(
+                "Idle",
+                Idle::default().timer_range(timer_range.0..timer_range.1),
+            ),
+            BehaviorNode::Wander { timer_range } => Behave::spawn_named(
+                "Wander",
+                Wander::builder().timer_range(timer_range.0..timer_range.1),
+            ),
+            BehaviorNode::Retreat => Behave::spawn_named("Retreat", Retreat),
+            BehaviorNode::Investigate => Behave::spawn_named("Investigate", Investigate),
+            BehaviorNode::Chase => Behave::spawn_named("Chase", Chase),
+            BehaviorNode::KeepDistanceAndFire => {
+                Behave::spawn_named("Keep distance and fire", KeepDistanceAndFire)
+            }
+            BehaviorNode::AttemptMelee => Behave::trigger(AttemptMelee),
+            BehaviorNode::Swoop => Behave::spawn_named("Swoop", Swoop::default()),
+            BehaviorNode::Hunt => Behave::spawn_named("Hunt", Hunt),
+            BehaviorNode::SupportAllies => Behave::spawn_named("Support allies", SupportAllies),
+            BehaviorNode::Flee { health_threshold } => {
+                Behave::spawn_named("Flee", Flee::new(*health_threshold))
+            }
+        };
+
+        Some(behave)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct BehaviorConfig {
+    trees: HashMap<String, BehaviorNode>,
+}
+
+fn setup_behavior_trees(mut commands: Commands) {
+    let trees = load_behavior_data();
+    commands.insert_resource(BehaviorTrees { trees });
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs::File;
+
+#[cfg(not(target_arch = "wasm32"))]
+fn fetch_behavior_data() -> File {
+    File::open("assets/config/behaviors.ron").expect("Failed to open RON file")
+}
+
+#[cfg(target_arch = "wasm32")]
+fn fetch_behavior_data() -> &'static [u8] {
+    include_bytes!("../../assets/config/behaviors.ron")
+}
+
+fn load_behavior_data() -> HashMap<String, BehaviorNode> {
+    let reader = BufReader::new(fetch_behavior_data());
+
+    let trees = from_reader::<_, BehaviorConfig>(reader)
+        .unwrap_or_else(|e| {
+            error!("Failed to parse RON file: {:?}", e);
+            panic!("RON parsing error");
+        })
+        .trees;
+
+    for (name, node) in &trees {
+        if let Err(e) = node.validate() {
+            error!("Failed to parse RON file, tree \"{name}\": {e}");
+            panic!("RON parsing error");
+        }
+    }
+
+    trees
+}
@@ -3,10 +3,7 @@ use bevy::prelude::*;
 
 use rand::{Rng, rng};
 
-use crate::{
-    character::{Purse, player::PlayerStats},
-    prelude::*,
-};
+use crate::{character::Purse, prelude::*};
 
 use crate::prelude::GoldDrop;
 
@@ -19,7 +16,7 @@ pub(super) fn on_enemy_defeated(
         (&Experience, &Transform, Option<&Items>, Option<&Purse>),
         With<Enemy>,
     >,
-    player_query: Single<(&PlayerStats, &mut Player)>,
+    player_query: Single<(Entity, &DerivedStats, &mut Player)>,
     item_query: Query<&Item>,
 ) {
     let mut rng = rng();
@@ -27,31 +24,36 @@ pub(super) fn on_enemy_defeated(
     if let Ok((experience_to_gain, transform, items, purse)) =
         defeated_enemy_query.get_mut(defeated.entity)
     {
-        let (player_stats, mut player) = player_query.into_inner();
-        //Give EXP to the player
-        player.current_experience += experience_to_gain.base_exp;
+        let (player_entity, derived_stats, mut player) = player_query.into_inner();
 
-        if let Some(items) = items {
-            for item_entity in items.iter() {
-                // Enemies drop their items based on drop rate
-                if let Ok(item_result) = item_query.get(item_entity) {
-                    let roll = rng.random_range(0.0..1.0);
-                    if roll > (1.0 - item_result.drop_rate) {
-                        commands.trigger(ItemDrop {
-                            entity: item_entity,
-                        });
+        // Only kills by the player pay out, not enemies finishing each other off
+        if defeated.instigator == Some(player_entity) {
+            //Give EXP to the player
+            player.current_experience += experience_to_gain.base_exp;
+
+            if let Some(items) = items {
+                for item_entity in items.iter() {
+                    // Enemies drop their items based on drop rate, boosted by player luck
+                    if let Ok(item_result) = item_query.get(item_entity) {
+                        let roll = rng.random_range(0.0..1.0);
+                        if roll > (1.0 - item_result.drop_rate * derived_stats.drop_rate_multiplier)
+                        {
+                            commands.trigger(ItemDrop {
+                                entity: item_entity,
+                            });
+                        }
                     }
                 }
             }
-        }
 
-        if let Some(purse) = purse {
-            // Enemies drop their gold based on player luck
-            if rng.random_range(0.0..1.0) < (0.1 + (player_stats.luck as f32 / 100.0)) {
-                commands.trigger(GoldDrop {
-                    location: transform.translation.truncate(),
-                    amount: purse.amount,
-                });
+            if let Some(purse) = purse {
+                // Enemies drop their gold based on player luck
+                if rng.random_range(0.0..1.0) < derived_stats.gold_drop_chance {
+                    commands.trigger(GoldDrop {
+                        location: transform.translation.truncate(),
+                        amount: purse.amount,
+                    });
+                }
             }
         }
 
@@ -0,0 +1,204 @@
+use std::{collections::HashMap, io::BufReader};
+
+use bevy::{prelude::*, scene::ron::de::from_reader};
+use serde::Deserialize;
+
+use crate::{
+    character::animation::AnimationSet,
+    prelude::{Resistances, SpriteAssets, SpriteSheetLayouts, StatusImmunities},
+};
+
+use super::{EnemyType, SummonDefinition};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Startup, setup_enemy_registry);
+}
+
+/// Every enemy archetype the game knows about, loaded from `config/enemies.ron`
+#[derive(Resource)]
+pub struct EnemyRegistry {
+    enemies: HashMap<EnemyType, EnemyDefinition>,
+}
+
+impl EnemyRegistry {
+    pub fn get(&self, enemy_type: &EnemyType) -> Option<&EnemyDefinition> {
+        self.enemies.get(enemy_type)
+    }
+
+    /// Regular enemy types, bosses are only spawned on purpose
+    pub fn enemy_types(&self) -> impl Iterator<Item = &EnemyType> {
+        self.enemies
+            .iter()
+            .filter(|(_, definition)| !definition.boss)
+            .map(|(enemy_type, _)| enemy_type)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct EnemyConfig {
+    enemies: HashMap<EnemyType, EnemyDefinition>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct EnemyDefinition {
+    pub display_name: String,
+    pub max_speed: f32,
+    pub health: f32,
+    pub experience: f32,
+    pub gold: u32,
+    /// How far the enemy can see, in pixels
+    pub vision_range: f32,
+    /// How well ranged attacks lead moving targets, 0.0 (sloppy) to 1.0 (perfect intercept)
+    pub accuracy: f32,
+    /// Damage types the enemy shrugs off or is weak to, ex. ice mages barely feel ice bolts
+    #[serde(default)]
+    pub resistances: Resistances,
+    /// Statuses that never take hold on the enemy
+    #[serde(default)]
+    pub status_immunities: StatusImmunities,
+    pub sprite: EnemySprite,
+    pub body: EnemyBody,
+    pub weapon: EnemyWeapon,
+    pub offhand: Option<EnemyOffhand>,
+    /// Minions raised while in a fight, `None` for enemies that fight alone
+    pub summon: Option<SummonDefinition>,
+    /// Items carried by the enemy, dropped based on their drop rate when defeated
+    pub loot: Vec<EnemyLoot>,
+    /// Name of a tree in `BehaviorTrees`
+    pub behavior: String,
+    #[serde(default)]
+    pub boss: bool,
+    /// Can't be hurt at all, the only way past the enemy is to get away from it
+    #[serde(default)]
+    pub invulnerable: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct EnemySprite {
+    sheet: EnemySpriteSheet,
+    pub(in crate::character) animation: AnimationSet,
+    /// Color the sheet is drawn with, so enemies sharing a sheet don't look alike
+    #[serde(default)]
+    tint: Option<(f32, f32, f32)>,
+    /// How many times bigger than a frame of the sheet the enemy is drawn
+    #[serde(default)]
+    scale: Option<f32>,
+}
+
+/// Sprite sheets loaded with `SpriteAssets`, so enemies never spawn before their textures are ready
+#[derive(Deserialize, Debug, Clone, Copy)]
+enum EnemySpriteSheet {
+    Warrior,
+    IceMage,
+    FireMage,
+    Bat,
+}
+
+impl EnemySpriteSheet {
+    fn image(self, sprites: &SpriteAssets) -> Handle<Image> {
+        match self {
+            EnemySpriteSheet::Warrior => sprites.warrior_enemy_sprite_sheet.clone(),
+            EnemySpriteSheet::IceMage => sprites.ice_mage_enemy_sprite_sheet.clone(),
+            EnemySpriteSheet::FireMage => sprites.fire_mage_enemy_sprite_sheet.clone(),
+            EnemySpriteSheet::Bat => sprites.bat_enemy_sprite_sheet.clone(),
+        }
+    }
+
+    fn layout(self, sprite_layouts: &SpriteSheetLayouts) -> Handle<TextureAtlasLayout> {
+        match self {
+            EnemySpriteSheet::Warrior | EnemySpriteSheet::IceMage | EnemySpriteSheet::FireMage => {
+                sprite_layouts.enemy_atlas_layout.clone()
+            }
+            EnemySpriteSheet::Bat => sprite_layouts.bat_enemy_layout.clone(),
+        }
+    }
+
+    /// Size of a single frame in the sheet's atlas layout
+    fn frame_size(self) -> Vec2 {
+        match self {
+            EnemySpriteSheet::Warrior | EnemySpriteSheet::IceMage | EnemySpriteSheet::FireMage => {
+                Vec2::splat(64.0)
+            }
+            EnemySpriteSheet::Bat => Vec2::splat(32.0),
+        }
+    }
+}
+
+impl EnemySprite {
+    pub fn tint(&self) -> Option<Color> {
+        self.tint.map(|(r, g, b)| Color::srgb(r, g, b))
+    }
+
+    pub fn sprite(&self, sprites: &SpriteAssets, sprite_layouts: &SpriteSheetLayouts) -> Sprite {
+        Sprite {
+            color: self.tint().unwrap_or(Color::WHITE),
+            custom_size: self.scale.map(|scale| self.sheet.frame_size() * scale),
+            ..Sprite::from_atlas_image(
+                self.sheet.image(sprites),
+                TextureAtlas {
+                    layout: self.sheet.layout(sprite_layouts),
+                    ..default()
+                },
+            )
+        }
+    }
+}
+
+/// Decides the enemies colliders and what it can move over
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub enum EnemyBody {
+    Grounded,
+    /// Flies over low obstacles like chests and water
+    Flying,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub enum EnemyWeapon {
+    Sword,
+    Axe,
+    FreezeAxe,
+    IceStaff,
+    FireStaff,
+    BatFangs,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub enum EnemyOffhand {
+    MendingTome,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub enum EnemyLoot {
+    HealthPotion,
+    Antidote,
+}
+
+fn setup_enemy_registry(mut commands: Commands) {
+    commands.insert_resource(EnemyRegistry {
+        enemies: load_enemy_data(),
+    });
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs::File;
+
+#[cfg(not(target_arch = "wasm32"))]
+fn fetch_enemy_data() -> File {
+    File::open("assets/config/enemies.ron").expect("Failed to open RON file")
+}
+
+#[cfg(target_arch = "wasm32")]
+fn fetch_enemy_data() -> &'static [u8] {
+    include_bytes!("../../../assets/config/enemies.ron")
+}
+
+fn load_enemy_data() -> HashMap<EnemyType, EnemyDefinition> {
+    let reader = BufReader::new(fetch_enemy_data());
+
+    from_reader::<_, EnemyConfig>(reader)
+        .unwrap_or_else(|e| {
+            error!("Failed to parse RON file: {:?}", e);
+            panic!("RON parsing error");
+        })
+        .enemies
+}
@@ -0,0 +1,291 @@
+use bevy::{
+    color::palettes::tailwind::{AMBER_300, ORANGE_500, RED_400},
+    prelude::*,
+};
+use bevy_lit::prelude::PointLight2d;
+use rand::{Rng, rng, seq::IndexedRandom};
+
+use crate::{character::Purse, prelude::*, ui::NamePlate};
+
+use super::{Enemy, EnemySpawnData, EnemyType, Experience, SpawnEnemies};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        burn_nearby_hostiles.in_set(InGameSystems::Simulation),
+    )
+    .add_observer(on_elite_spawned)
+    .add_observer(on_elite_defeated);
+}
+
+const BASE_ELITE_CHANCE: f32 = 0.05;
+const ELITE_CHANCE_PER_DEPTH: f32 = 0.05;
+const MAX_ELITE_CHANCE: f32 = 0.5;
+/// Chance an elite is promoted to champion, also scaling with depth
+const CHAMPION_CHANCE_PER_DEPTH: f32 = 0.05;
+const MAX_CHAMPION_CHANCE: f32 = 0.3;
+
+const BURNING_AURA_RADIUS: f32 = 64.0;
+const REFLECTION_AURA_RADIUS: f32 = 28.0;
+const SPLIT_COUNT: usize = 2;
+const SPLIT_OFFSET: f32 = 24.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EliteRank {
+    Elite,
+    /// Stronger elite with more affixes and better rewards
+    Champion,
+}
+
+impl EliteRank {
+    fn affix_count(self) -> usize {
+        match self {
+            EliteRank::Elite => 1,
+            EliteRank::Champion => 2,
+        }
+    }
+
+    fn health_multiplier(self) -> f32 {
+        match self {
+            EliteRank::Elite => 1.5,
+            EliteRank::Champion => 2.0,
+        }
+    }
+
+    fn reward_multiplier(self) -> f32 {
+        match self {
+            EliteRank::Elite => 3.0,
+            EliteRank::Champion => 5.0,
+        }
+    }
+
+    fn tint(self) -> Color {
+        match self {
+            EliteRank::Elite => Color::from(AMBER_300),
+            EliteRank::Champion => Color::from(RED_400),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EliteAffix {
+    /// Double health on top of the rank bonus
+    Hardy,
+    /// Moves much faster
+    Swift,
+    /// Sets nearby hostiles on fire
+    Burning,
+    /// Reflects projectiles like an active magic shield
+    Reflective,
+    /// Splits into regular enemies of the same type when defeated
+    Splitting,
+}
+
+impl EliteAffix {
+    const ALL: [EliteAffix; 5] = [
+        EliteAffix::Hardy,
+        EliteAffix::Swift,
+        EliteAffix::Burning,
+        EliteAffix::Reflective,
+        EliteAffix::Splitting,
+    ];
+
+    fn display_name(self) -> &'static str {
+        match self {
+            EliteAffix::Hardy => "Hardy",
+            EliteAffix::Swift => "Swift",
+            EliteAffix::Burning => "Burning",
+            EliteAffix::Reflective => "Reflective",
+            EliteAffix::Splitting => "Splitting",
+        }
+    }
+}
+
+/// Marks an enemy as an elite, its affixes get applied once it is spawned
+#[derive(Component, Debug, Clone)]
+pub struct Elite {
+    pub rank: EliteRank,
+    pub affixes: Vec<EliteAffix>,
+}
+
+impl Elite {
+    /// Rolls whether a spawning enemy should be an elite, deeper zones roll more (and stronger) elites
+    pub fn roll(zone_difficulty: &ZoneDifficulty) -> Option<Elite> {
+        let mut rng = rng();
+        let depth = zone_difficulty.depth as f32;
+
+        let elite_chance =
+            (BASE_ELITE_CHANCE + depth * ELITE_CHANCE_PER_DEPTH).min(MAX_ELITE_CHANCE);
+        if !rng.random_bool(elite_chance.into()) {
+            return None;
+        }
+
+        let champion_chance = (depth * CHAMPION_CHANCE_PER_DEPTH).min(MAX_CHAMPION_CHANCE);
+        let rank = if rng.random_bool(champion_chance.into()) {
+            EliteRank::Champion
+        } else {
+            EliteRank::Elite
+        };
+
+        let affixes = EliteAffix::ALL
+            .choose_multiple(&mut rng, rank.affix_count())
+            .copied()
+            .collect();
+
+        Some(Elite { rank, affixes })
+    }
+
+    fn name(&self, enemy_name: &str) -> String {
+        let affixes: Vec<&str> = self
+            .affixes
+            .iter()
+            .map(|affix| affix.display_name())
+            .collect();
+
+        format!("{} {enemy_name}", affixes.join(" "))
+    }
+}
+
+/// Periodically applies its `Effects` to hostile characters near the elite it belongs to
+#[derive(Component)]
+struct BurningAura {
+    radius: f32,
+    frequency: Timer,
+}
+
+fn burning_aura() -> impl Bundle {
+    (
+        Name::new("Burning Aura"),
+        BurningAura {
+            radius: BURNING_AURA_RADIUS,
+            frequency: Timer::from_seconds(1.0, TimerMode::Repeating),
+        },
+        Transform::default(),
+        PointLight2d {
+            color: Color::from(ORANGE_500),
+            intensity: 2.0,
+            falloff: 5.0,
+            outer_radius: BURNING_AURA_RADIUS * 1.5,
+            ..default()
+        },
+        related!(Effects[(Burning, Lifespan::new(2.0))]),
+    )
+}
+
+fn on_elite_spawned(
+    elite_spawned: On<Add, Elite>,
+    mut commands: Commands,
+    mut elite_query: Query<
+        (
+            &Elite,
+            &Name,
+            &mut Health,
+            &mut SimpleMotion,
+            &mut Experience,
+            &mut Purse,
+            &mut Sprite,
+        ),
+        With<Enemy>,
+    >,
+) {
+    let Ok((elite, name, mut health, mut motion, mut experience, mut purse, mut sprite)) =
+        elite_query.get_mut(elite_spawned.entity)
+    else {
+        return;
+    };
+
+    let mut health_multiplier = elite.rank.health_multiplier();
+    let reward_multiplier = elite.rank.reward_multiplier();
+
+    experience.base_exp *= reward_multiplier;
+    purse.amount = (purse.amount as f32 * reward_multiplier) as u32;
+
+    let tint = elite.rank.tint();
+    sprite.color = tint;
+
+    let mut elite_commands = commands.entity(elite_spawned.entity);
+    elite_commands.insert((
+        Tint(tint),
+        NamePlate {
+            name: elite.name(name.as_str()),
+            color: tint,
+        },
+    ));
+
+    for affix in &elite.affixes {
+        match affix {
+            EliteAffix::Hardy => health_multiplier *= 2.0,
+            EliteAffix::Swift => motion.max_speed *= 1.4,
+            EliteAffix::Burning => {
+                elite_commands.with_child(burning_aura());
+            }
+            EliteAffix::Reflective => {
+                elite_commands.with_child(reflection_aura(REFLECTION_AURA_RADIUS));
+            }
+            // Handled once the elite is defeated
+            EliteAffix::Splitting => {}
+        }
+    }
+
+    health.max_hp *= health_multiplier;
+    health.hp = health.max_hp;
+}
+
+fn burn_nearby_hostiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut aura_query: Query<(&mut BurningAura, &Effects, &ChildOf)>,
+    owner_query: Query<(&Transform, &Faction)>,
+    target_query: Query<(Entity, &Transform, &Faction, &Health)>,
+) {
+    for (mut aura, effects, child_of) in &mut aura_query {
+        if !aura.frequency.tick(time.delta()).just_finished() {
+            continue;
+        }
+
+        let Ok((owner_transform, owner_faction)) = owner_query.get(child_of.parent()) else {
+            continue;
+        };
+
+        let aura_position = owner_transform.translation.xy();
+
+        for (target, target_transform, target_faction, target_health) in &target_query {
+            if owner_faction.is_hostile_to(*target_faction)
+                && !target_health.is_dead()
+                && target_transform.translation.xy().distance(aura_position) <= aura.radius
+            {
+                commands.trigger(
+                    ApplyEffects::new(effects, target).with_instigator(Some(child_of.parent())),
+                );
+            }
+        }
+    }
+}
+
+fn on_elite_defeated(
+    defeated: On<Defeated>,
+    mut commands: Commands,
+    elite_query: Query<(&Elite, &EnemyType, &Transform)>,
+) {
+    let Ok((elite, enemy_type, transform)) = elite_query.get(defeated.entity) else {
+        return;
+    };
+
+    if !elite.affixes.contains(&EliteAffix::Splitting) {
+        return;
+    }
+
+    let position = transform.translation.xy();
+    let split_spawns = (0..SPLIT_COUNT)
+        .map(|i| EnemySpawnData {
+            position: position
+                + Vec2::from_angle(std::f32::consts::TAU * i as f32 / SPLIT_COUNT as f32)
+                    * SPLIT_OFFSET,
+            enemy_type: enemy_type.clone(),
+            elite: None,
+            spawned_by: None,
+        })
+        .collect();
+
+    commands.trigger(SpawnEnemies(split_spawns));
+}
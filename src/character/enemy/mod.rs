@@ -3,18 +3,29 @@ use bevy::{prelude::*, ui_widgets::observe};
 use bevy_behave::prelude::*;
 
 mod defeat;
+mod definition;
+mod elite;
+mod summoner;
+
+use serde::Deserialize;
 
 use crate::{
     character::{
-        Character, Purse,
-        behavior::{Anchor, AttemptMelee, Chase, Idle, KeepDistanceAndFire, Retreat, Wander},
-        physical_collider,
-        vision::{VisionCapabilities, Watching},
+        Character, Purse, behavior::Anchor, flying_collider, hearing::Hearing, physical_collider,
+        vision::VisionCapabilities,
     },
     prelude::*,
 };
 
+pub use definition::{
+    EnemyBody, EnemyDefinition, EnemyLoot, EnemyOffhand, EnemyRegistry, EnemyWeapon,
+};
+pub use elite::{Elite, EliteAffix, EliteRank};
+pub use summoner::{Minion, SummonDefinition, Summoner};
+
 pub(super) fn plugin(app: &mut App) {
+    app.add_plugins((definition::plugin, elite::plugin, summoner::plugin));
+
     app.add_observer(spawn_enemies);
 
     app.add_observer(despawn_all::<CleanupZone, Enemy>);
@@ -27,22 +38,44 @@ pub struct SpawnEnemies(pub Vec<EnemySpawnData>);
 pub struct EnemySpawnData {
     pub position: Vec2,
     pub enemy_type: EnemyType,
+    /// Rolled when the spawn is created, `None` for a regular enemy
+    pub elite: Option<Elite>,
+    /// Whatever brought this enemy into the world (ex. a spawner), so it can keep track of it
+    pub spawned_by: Option<Entity>,
 }
 
+#[derive(Component, Clone)]
+#[relationship(relationship_target = SpawnedEnemies)]
+pub struct SpawnedBy(pub Entity);
+
+#[derive(Component, Clone)]
+#[relationship_target(relationship = SpawnedBy)]
+pub struct SpawnedEnemies(Vec<Entity>);
+
 #[derive(Component)]
 #[require(
     Character,
     Experience,
     VisionCapabilities,
+    Hearing,
+    Faction::Enemy,
     Purse { amount: 50 },
+    Steering,
 )]
 pub struct Enemy;
 
-#[derive(Component, PartialEq, Clone, Debug)]
-pub enum EnemyType {
-    Warrior,
-    IceMage,
-    FireMage,
+/// Marks the big bad of an area, gets a health bar across the top of the screen
+#[derive(Component)]
+pub struct Boss;
+
+/// ID of an enemy archetype in the `EnemyRegistry`
+#[derive(Component, Deserialize, PartialEq, Eq, Hash, Clone, Debug)]
+pub struct EnemyType(pub String);
+
+impl From<&str> for EnemyType {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
 }
 
 //Experience granted by the enemy when player defeats it
@@ -60,19 +93,26 @@ impl Default for Experience {
 fn spawn_enemies(
     spawn_enemies: On<SpawnEnemies>,
     mut commands: Commands,
+    registry: Res<EnemyRegistry>,
+    behavior_trees: Res<BehaviorTrees>,
     sprites: Res<SpriteAssets>,
     sprite_layouts: Res<SpriteSheetLayouts>,
     shadows: Res<Shadows>,
-    player: Single<Entity, With<Player>>,
 ) {
     for spawn_data in spawn_enemies.0.clone() {
+        let Some(definition) = registry.get(&spawn_data.enemy_type) else {
+            warn!("Unknown enemy type: {:?}", spawn_data.enemy_type);
+            continue;
+        };
+
         spawn_enemy(
             &mut commands,
             spawn_data,
+            definition,
+            &behavior_trees,
             &sprites,
             &sprite_layouts,
             &shadows,
-            player.entity(),
         );
     }
 }
@@ -80,159 +120,155 @@ fn spawn_enemies(
 fn spawn_enemy(
     commands: &mut Commands,
     spawn_data: EnemySpawnData,
+    definition: &EnemyDefinition,
+    behavior_trees: &BehaviorTrees,
     sprites: &SpriteAssets,
     sprite_layouts: &SpriteSheetLayouts,
     shadows: &Shadows,
-    player: Entity,
 ) {
     trace!("Spawning enemy at: {}", spawn_data.position);
 
-    let chase_behavior = behave! {
-        Behave::While => {
-            Behave::spawn_named("Chase", Chase),
-            Behave::trigger(AttemptMelee)
-        }
+    let Some(behavior) = behavior_trees.build(&definition.behavior) else {
+        warn!("{} has no valid behaviour tree", definition.display_name);
+        return;
     };
 
-    let melee_enemy_behavior = behave! {
-        Behave::Forever => {
-            Behave::Fallback => {
-                Behave::Sequence => {
-                    Behave::spawn_named("Wander", Wander::builder().timer_range(1.0..2.0)),
-                    Behave::spawn_named("Idle", Idle::default().timer_range(3.0..5.0)),
-                },
-                Behave::spawn_named("Retreat", Retreat),
-                @chase_behavior
-            }
-        }
-    };
+    let enemy = commands
+        .spawn((
+            enemy_archetype(definition, sprites, sprite_layouts),
+            base_enemy(spawn_data.position, definition.vision_range),
+        ))
+        .id();
 
-    let ranged_enemy_behavior = behave! {
-        Behave::Forever => {
-            Behave::Fallback => {
-                Behave::Sequence => {
-                    Behave::spawn_named("Wander", Wander::builder().timer_range(1.0..2.0)),
-                    Behave::spawn_named("Idle", Idle::default().timer_range(3.0..5.0)),
-                },
-                Behave::spawn_named("Retreat", Retreat),
-                Behave::spawn_named("Keep distance and fire", KeepDistanceAndFire)
-            }
-        }
+    match definition.body {
+        EnemyBody::Grounded => commands
+            .entity(enemy)
+            .insert(enemy_children(behavior, shadows)),
+        EnemyBody::Flying => commands
+            .entity(enemy)
+            .insert((Steering::flying(), flying_children(behavior, shadows))),
     };
 
-    match spawn_data.enemy_type {
-        EnemyType::Warrior => spawn_enemy_with_equipment(
-            commands,
-            (
-                warrior(sprites, sprite_layouts),
-                base_enemy(spawn_data.position, player),
-                enemy_children(melee_enemy_behavior, shadows),
-            ),
-            sword(sprites),
-        ),
-
-        EnemyType::IceMage => spawn_enemy_with_equipment(
-            commands,
-            (
-                ice_mage(sprites, sprite_layouts),
-                base_enemy(spawn_data.position, player),
-                enemy_children(ranged_enemy_behavior, shadows),
-            ),
-            ice_staff(sprites, sprite_layouts),
-        ),
-
-        EnemyType::FireMage => spawn_enemy_with_equipment(
-            commands,
-            (
-                fire_mage(sprites, sprite_layouts),
-                base_enemy(spawn_data.position, player),
-                enemy_children(ranged_enemy_behavior, shadows),
-            ),
-            fire_staff(sprites, sprite_layouts),
-        ),
+    for loot in &definition.loot {
+        match loot {
+            EnemyLoot::HealthPotion => commands.spawn((health_potion(sprites), ItemOf(enemy))),
+            EnemyLoot::Antidote => commands.spawn((antidote(sprites), ItemOf(enemy))),
+        };
     }
-}
 
-fn spawn_enemy_with_equipment(commands: &mut Commands, enemy: impl Bundle, mainhand: impl Bundle) {
-    let enemy = commands.spawn(enemy).id();
-
-    let mainhand = commands.spawn(mainhand).id();
+    let mainhand = match definition.weapon {
+        EnemyWeapon::Sword => commands.spawn(sword(sprites)),
+        EnemyWeapon::Axe => commands.spawn(axe(sprites)),
+        EnemyWeapon::FreezeAxe => commands.spawn(freeze_axe(sprites)),
+        EnemyWeapon::IceStaff => commands.spawn(ice_staff(sprites, sprite_layouts)),
+        EnemyWeapon::FireStaff => commands.spawn(fire_staff(sprites, sprite_layouts)),
+        EnemyWeapon::BatFangs => commands.spawn(bat_fangs()),
+    }
+    .id();
 
     commands.trigger(Equip {
         item: mainhand,
         holder: enemy,
     });
+
+    if let Some(offhand) = definition.offhand {
+        let offhand = match offhand {
+            EnemyOffhand::MendingTome => commands.spawn(tome_of_mending(sprites)),
+        }
+        .id();
+
+        commands.trigger(Equip {
+            item: offhand,
+            holder: enemy,
+        });
+    }
+
+    commands.entity(enemy).insert(spawn_data.enemy_type);
+
+    if let Some(elite) = spawn_data.elite {
+        commands.entity(enemy).insert(elite);
+    }
+
+    if definition.boss {
+        commands.entity(enemy).insert(Boss);
+    }
+
+    if definition.invulnerable {
+        commands.entity(enemy).insert(Invulnerable);
+    }
+
+    // Damage flashes fade back to the tint instead of plain white
+    if let Some(tint) = definition.sprite.tint() {
+        commands.entity(enemy).insert(Tint(tint));
+    }
+
+    if let Some(summon) = &definition.summon {
+        commands.entity(enemy).insert(Summoner::from(summon));
+    }
+
+    if let Some(spawned_by) = spawn_data.spawned_by {
+        commands.entity(enemy).insert(SpawnedBy(spawned_by));
+    }
 }
 
-fn base_enemy(position: Vec2, player: Entity) -> impl Bundle {
+fn base_enemy(position: Vec2, vision_range: f32) -> impl Bundle {
     (
         Enemy,
         Transform::from_translation(position.extend(ZLayer::OnGround.z())),
         Anchor::new(position, 256.0), // 8 tile radius
         Mana::new(100.0, 10.0),
-        // enemy vision distance
+        VisionCapabilities {
+            sight_range: vision_range,
+            ..default()
+        },
         RayCaster::default()
-            .with_max_distance(350.0)
-            .with_query_filter(SpatialQueryFilter::from_mask([
-                GameCollisionLayer::AllyHurtBox,
-                GameCollisionLayer::HighObstacle,
-            ]))
+            .with_max_distance(vision_range)
+            .with_query_filter(SpatialQueryFilter::from_mask(
+                Faction::Enemy.hostile_layers() | GameCollisionLayer::HighObstacle,
+            ))
             .with_max_hits(1),
-        Watching(player),
         observe(defeat::on_enemy_defeated),
     )
 }
 
+fn enemy_archetype(
+    definition: &EnemyDefinition,
+    sprites: &SpriteAssets,
+    sprite_layouts: &SpriteSheetLayouts,
+) -> impl Bundle {
+    (
+        Name::new(definition.display_name.clone()),
+        SimpleMotion::new(definition.max_speed),
+        Health::new(definition.health),
+        Experience {
+            base_exp: definition.experience,
+        },
+        Purse {
+            amount: definition.gold,
+        },
+        Accuracy(definition.accuracy),
+        definition.resistances.clone(),
+        definition.status_immunities.clone(),
+        definition.sprite.animation,
+        definition.sprite.sprite(sprites, sprite_layouts),
+    )
+}
+
 fn enemy_children(behavior: Tree<Behave>, shadows: &Shadows) -> impl Bundle {
     children![
         shadow(shadows, CHARACTER_FEET_POS_OFFSET - 4.0),
         physical_collider(),
-        hurtbox(Vec2::new(26.0, 42.0), GameCollisionLayer::EnemyHurtBox),
+        hurtbox(Vec2::new(26.0, 42.0), Faction::Enemy),
         BehaveTree::new(behavior.clone()),
     ]
 }
 
-fn warrior(sprites: &SpriteAssets, sprite_layouts: &SpriteSheetLayouts) -> impl Bundle {
-    (
-        SimpleMotion::new(200.0),
-        Health::new(40.0),
-        Sprite::from_atlas_image(
-            sprites.warrior_enemy_sprite_sheet.clone(),
-            TextureAtlas {
-                layout: sprite_layouts.enemy_atlas_layout.clone(),
-                ..default()
-            },
-        ),
-        related!(Items[health_potion(sprites)]),
-    )
-}
-
-fn ice_mage(sprites: &SpriteAssets, sprite_layouts: &SpriteSheetLayouts) -> impl Bundle {
-    (
-        SimpleMotion::new(100.0),
-        Health::new(20.0),
-        Sprite::from_atlas_image(
-            sprites.ice_mage_enemy_sprite_sheet.clone(),
-            TextureAtlas {
-                layout: sprite_layouts.enemy_atlas_layout.clone(),
-                ..default()
-            },
-        ),
-        related!(Items[health_potion(sprites)]),
-    )
-}
-
-fn fire_mage(sprites: &SpriteAssets, sprite_layouts: &SpriteSheetLayouts) -> impl Bundle {
-    (
-        SimpleMotion::new(150.0),
-        Health::new(20.0),
-        Sprite::from_atlas_image(
-            sprites.fire_mage_enemy_sprite_sheet.clone(),
-            TextureAtlas {
-                layout: sprite_layouts.enemy_atlas_layout.clone(),
-                ..default()
-            },
-        ),
-        related!(Items[health_potion(sprites)]),
-    )
+fn flying_children(behavior: Tree<Behave>, shadows: &Shadows) -> impl Bundle {
+    children![
+        // Shadow is further from the sprite to sell that the enemy is flying
+        shadow(shadows, CHARACTER_FEET_POS_OFFSET),
+        flying_collider(),
+        hurtbox(Vec2::new(20.0, 16.0), Faction::Enemy),
+        BehaveTree::new(behavior.clone()),
+    ]
 }
@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+use rand::{Rng, rng};
+use serde::Deserialize;
+
+use crate::{
+    character::{Purse, vision::Targeting},
+    prelude::*,
+};
+
+use super::{EnemySpawnData, EnemyType, Experience, SpawnEnemies, SpawnedBy, SpawnedEnemies};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Update, summon_minions.in_set(InGameSystems::Simulation))
+        .add_observer(on_minion_summoned)
+        .add_observer(on_summoner_defeated);
+}
+
+/// Minions appear in a ring this far from the summoner
+const SUMMON_RADIUS: f32 = 40.0;
+
+/// How an enemy archetype raises minions, from `config/enemies.ron`
+#[derive(Deserialize, Debug, Clone)]
+pub struct SummonDefinition {
+    pub minion: EnemyType,
+    /// Seconds between summons
+    pub interval: f32,
+    pub minions_per_summon: usize,
+    /// Summoning is skipped while this many minions are still alive
+    pub max_minions: usize,
+    /// Seconds a minion lasts before it crumbles
+    pub minion_lifespan: f32,
+}
+
+/// Periodically raises minions while it has a target. Minions are tied to the summoner through `SpawnedBy`
+/// and go down with it
+#[derive(Component)]
+pub struct Summoner {
+    minion: EnemyType,
+    timer: Timer,
+    minions_per_summon: usize,
+    max_minions: usize,
+    minion_lifespan: f32,
+}
+
+impl From<&SummonDefinition> for Summoner {
+    fn from(definition: &SummonDefinition) -> Self {
+        Self {
+            minion: definition.minion.clone(),
+            timer: Timer::from_seconds(definition.interval, TimerMode::Repeating),
+            minions_per_summon: definition.minions_per_summon,
+            max_minions: definition.max_minions,
+            minion_lifespan: definition.minion_lifespan,
+        }
+    }
+}
+
+/// Raised by a `Summoner`, doesn't last long
+#[derive(Component)]
+pub struct Minion;
+
+fn summon_minions(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut summoner_query: Query<
+        (
+            Entity,
+            &mut Summoner,
+            &Transform,
+            &Health,
+            Option<&SpawnedEnemies>,
+        ),
+        With<Targeting>,
+    >,
+    health_query: Query<&Health>,
+) {
+    let mut rng = rng();
+
+    for (entity, mut summoner, transform, health, minions) in &mut summoner_query {
+        if health.is_dead() || !summoner.timer.tick(time.delta()).just_finished() {
+            continue;
+        }
+
+        let alive = minions
+            .into_iter()
+            .flat_map(SpawnedEnemies::iter)
+            .filter(|&minion| health_query.get(minion).is_ok_and(|h| !h.is_dead()))
+            .count();
+
+        let count = summoner
+            .minions_per_summon
+            .min(summoner.max_minions.saturating_sub(alive));
+        if count == 0 {
+            continue;
+        }
+
+        let position = transform.translation.xy();
+        let spawn_data = (0..count)
+            .map(|_| {
+                let angle = rng.random_range(0.0..std::f32::consts::TAU);
+                EnemySpawnData {
+                    position: position + Vec2::from_angle(angle) * SUMMON_RADIUS,
+                    enemy_type: summoner.minion.clone(),
+                    elite: None,
+                    spawned_by: Some(entity),
+                }
+            })
+            .collect();
+
+        commands.trigger(SpawnEnemies(spawn_data));
+    }
+}
+
+fn on_minion_summoned(
+    spawned: On<Add, SpawnedBy>,
+    mut commands: Commands,
+    spawned_query: Query<&SpawnedBy>,
+    summoner_query: Query<&Summoner>,
+) -> Result {
+    let spawned_by = spawned_query.get(spawned.entity)?;
+
+    // Spawners and other sources use the same relationship, only summoned enemies are minions
+    if let Ok(summoner) = summoner_query.get(spawned_by.0) {
+        // No rewards, otherwise a summoner could be farmed forever
+        commands.entity(spawned.entity).insert((
+            Minion,
+            Lifespan::new(summoner.minion_lifespan),
+            Experience { base_exp: 0.0 },
+            Purse { amount: 0 },
+        ));
+    }
+    Ok(())
+}
+
+fn on_summoner_defeated(
+    defeated: On<Defeated>,
+    mut commands: Commands,
+    summoner_query: Query<Option<&SpawnedEnemies>, With<Summoner>>,
+    minion_query: Query<(), With<Minion>>,
+) {
+    let Ok(Some(minions)) = summoner_query.get(defeated.entity) else {
+        return;
+    };
+
+    for minion in minions.iter().filter(|m| minion_query.contains(*m)) {
+        commands.entity(minion).despawn();
+    }
+}
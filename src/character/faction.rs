@@ -0,0 +1,72 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(on_faction_changed);
+}
+
+/// Which side of the fight a character is on. Everything about "who can hurt whom" is derived from this:
+/// hurtbox layers, what projectiles and melee weapons collide with, and who the AI considers a target.
+///
+/// To charm an enemy or turn an NPC against the player, just insert a new `Faction`.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Faction {
+    /// The player and anyone fighting alongside them (NPCs, summons, etc...)
+    Ally,
+    Enemy,
+}
+
+impl Faction {
+    const ALL: [Faction; 2] = [Faction::Ally, Faction::Enemy];
+
+    /// Row is the attacking faction, column is the defending faction
+    const HOSTILITY: [[bool; 2]; 2] = [
+        //  Ally   Enemy
+        [false, true], // Ally
+        [true, false], // Enemy
+    ];
+
+    pub fn is_hostile_to(self, other: Faction) -> bool {
+        Self::HOSTILITY[self as usize][other as usize]
+    }
+
+    /// Layer that hurtboxes of this faction are a member of
+    pub fn hurtbox_layer(self) -> GameCollisionLayer {
+        match self {
+            Faction::Ally => GameCollisionLayer::AllyHurtBox,
+            Faction::Enemy => GameCollisionLayer::EnemyHurtBox,
+        }
+    }
+
+    /// All hurtbox layers this faction is allowed to damage
+    pub fn hostile_layers(self) -> LayerMask {
+        Self::ALL
+            .into_iter()
+            .filter(|&other| self.is_hostile_to(other))
+            .fold(LayerMask::NONE, |mask, other| mask | other.hurtbox_layer())
+    }
+}
+
+/// Keeps hurtboxes and vision in sync when a character switches sides (ex. charmed)
+fn on_faction_changed(
+    faction_changed: On<Insert, Faction>,
+    faction_query: Query<(&Faction, Option<&Children>)>,
+    mut hurtbox_query: Query<&mut CollisionLayers, With<HurtBox>>,
+    mut vision_query: Query<&mut RayCaster>,
+) {
+    let Ok((faction, children)) = faction_query.get(faction_changed.entity) else {
+        return;
+    };
+
+    for child in children.into_iter().flatten() {
+        if let Ok(mut layers) = hurtbox_query.get_mut(*child) {
+            layers.memberships = faction.hurtbox_layer().into();
+        }
+    }
+
+    if let Ok(mut ray_caster) = vision_query.get_mut(faction_changed.entity) {
+        ray_caster.query_filter.mask = faction.hostile_layers() | GameCollisionLayer::HighObstacle;
+    }
+}
@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+
+use crate::{character::vision::Targeting, prelude::*};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (emit_footstep_noise, forget_heard_noise).in_set(InGameSystems::Simulation),
+    )
+    .add_observer(on_noise);
+}
+
+/// Sent any time something in the world makes a sound (swinging a sword, casting a spell, opening a chest...)
+#[derive(Event)]
+pub struct Noise {
+    /// World position the sound came from
+    pub position: Vec2,
+    /// Distance (in pixels) the sound carries before it can no longer be heard
+    pub loudness: f32,
+    /// Entity responsible for the sound, if any. Listeners only react to sounds made by hostile factions
+    pub source: Option<Entity>,
+}
+
+impl Noise {
+    pub const FOOTSTEP: f32 = 96.0;
+    pub const MELEE_SWING: f32 = 192.0;
+    pub const SPELL_CAST: f32 = 256.0;
+    pub const CHEST_OPEN: f32 = 320.0;
+
+    pub fn new(position: Vec2, loudness: f32, source: Entity) -> Self {
+        Self {
+            position,
+            loudness,
+            source: Some(source),
+        }
+    }
+}
+
+/// Lets an entity perceive `Noise` events, even without line of sight
+#[derive(Component)]
+pub(super) struct Hearing {
+    /// Multiplier on how far away a noise can be heard from
+    pub acuity: f32,
+}
+
+impl Default for Hearing {
+    fn default() -> Self {
+        Self { acuity: 1.0 }
+    }
+}
+
+/// The last noise an entity heard and has not finished investigating yet
+#[derive(Component)]
+pub struct HeardNoise {
+    pub position: Vec2,
+    /// How long until the listener gives up and forgets about the noise
+    memory: Timer,
+}
+
+impl HeardNoise {
+    fn new(position: Vec2) -> Self {
+        Self {
+            position,
+            memory: Timer::from_seconds(5.0, TimerMode::Once),
+        }
+    }
+}
+
+/// Emits a quiet noise at a regular interval while the character is moving
+#[derive(Component)]
+pub struct Footsteps(Timer);
+
+impl Default for Footsteps {
+    fn default() -> Self {
+        Self(Timer::from_seconds(0.4, TimerMode::Repeating))
+    }
+}
+
+/// Listeners that are not already targeting someone will turn toward and investigate
+/// noises made by anyone hostile to them
+fn on_noise(
+    noise: On<Noise>,
+    mut commands: Commands,
+    listener_query: Query<(Entity, &Hearing, &Transform, &Faction), Without<Targeting>>,
+    source_query: Query<&Faction>,
+) {
+    let source_faction = noise
+        .source
+        .and_then(|source| source_query.get(source).ok());
+
+    for (listener, hearing, transform, faction) in &listener_query {
+        if source_faction.is_some_and(|source_faction| !faction.is_hostile_to(*source_faction)) {
+            continue;
+        }
+
+        let distance = transform.translation.xy().distance(noise.position);
+
+        if distance <= noise.loudness * hearing.acuity {
+            commands
+                .entity(listener)
+                .insert(HeardNoise::new(noise.position));
+        }
+    }
+}
+
+fn emit_footstep_noise(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut footsteps_query: Query<(Entity, &mut Footsteps, &SimpleMotion, &Transform)>,
+) {
+    for (entity, mut footsteps, motion, transform) in &mut footsteps_query {
+        if motion.is_moving() && footsteps.0.tick(time.delta()).just_finished() {
+            commands.trigger(Noise::new(
+                transform.translation.xy(),
+                Noise::FOOTSTEP,
+                entity,
+            ));
+        }
+    }
+}
+
+/// Once a target is found or enough time has passed the noise is no longer interesting
+fn forget_heard_noise(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut heard_query: Query<(Entity, &mut HeardNoise, Has<Targeting>)>,
+) {
+    for (entity, mut heard_noise, has_target) in &mut heard_query {
+        if has_target || heard_noise.memory.tick(time.delta()).is_finished() {
+            commands.entity(entity).remove::<HeardNoise>();
+        }
+    }
+}
@@ -1,16 +1,24 @@
 mod animation;
 mod behavior;
+mod behavior_tree;
 mod enemy;
+mod faction;
+mod hearing;
 mod npc;
 mod player;
 mod state;
+mod steering;
 mod vision;
 
 pub mod prelude {
+    pub use super::behavior_tree::BehaviorTrees;
     pub use super::enemy::*;
+    pub use super::faction::Faction;
+    pub use super::hearing::{Footsteps, HeardNoise, Noise};
     pub use super::npc::*;
     pub use super::player::prelude::*;
     pub use super::state::*;
+    pub use super::steering::Steering;
     pub use super::vision::*;
 }
 
@@ -25,11 +33,20 @@ impl Plugin for CharacterPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((player::plugin, enemy::plugin, npc::plugin));
 
-        app.add_plugins((animation::plugin, behavior::plugin, vision::plugin));
+        app.add_plugins((
+            animation::plugin,
+            behavior::plugin,
+            behavior_tree::plugin,
+            faction::plugin,
+            hearing::plugin,
+            vision::plugin,
+        ));
 
         app.add_systems(
             FixedUpdate,
-            state::motion_to_velocity.in_set(MainSystems::InGame),
+            (steering::steer_around_obstacles, state::motion_to_velocity)
+                .chain()
+                .in_set(MainSystems::InGame),
         );
     }
 }
@@ -63,6 +80,18 @@ pub fn physical_collider() -> impl Bundle {
     )
 }
 
+/// Collider for characters that fly over low obstacles (chests, water) but are still stopped by walls
+pub fn flying_collider() -> impl Bundle {
+    (
+        Transform::from_xyz(0.0, CHARACTER_FEET_POS_OFFSET / 2.0, 0.0),
+        Collider::circle(8.0),
+        CollisionLayers::new(
+            [GameCollisionLayer::InAir],
+            [GameCollisionLayer::HighObstacle],
+        ),
+    )
+}
+
 #[derive(Component, Clone, Default)]
 pub struct Purse {
     pub amount: u32,
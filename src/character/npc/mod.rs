@@ -4,15 +4,11 @@ use bevy_behave::prelude::*;
 mod interaction;
 
 use crate::{
-    character::{
-        Character,
-        behavior::{Idle, Retreat},
-        physical_collider,
-    },
+    character::{Character, physical_collider},
     prelude::*,
 };
 
-use super::behavior::{Anchor, Wander};
+use super::behavior::Anchor;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_observer(spawn_npcs)
@@ -23,7 +19,7 @@ pub(super) fn plugin(app: &mut App) {
 pub struct SpawnNpcs(pub Vec<Vec2>);
 
 #[derive(Component)]
-#[require(Character)]
+#[require(Character, Faction::Ally, Steering)]
 pub struct NPC;
 
 #[derive(Clone, Copy, Debug)]
@@ -33,6 +29,15 @@ enum NPCType {
     StatTrainer,
 }
 
+impl NPCType {
+    /// Name of the tree in `BehaviorTrees` this NPC runs
+    fn behavior(self) -> &'static str {
+        match self {
+            NPCType::Helper | NPCType::Shopkeeper | NPCType::StatTrainer => "npc_wander",
+        }
+    }
+}
+
 const TILE_SIZE: f32 = 32.0;
 const WANDER_RADIUS: f32 = 2.5 * TILE_SIZE;
 
@@ -42,6 +47,7 @@ fn spawn_npcs(
     sprites: Res<SpriteAssets>,
     sprite_layouts: Res<SpriteSheetLayouts>,
     shadows: Res<Shadows>,
+    behavior_trees: Res<BehaviorTrees>,
 ) {
     // Define the NPC types we want to spawn in order
     let npc_types = [NPCType::Helper, NPCType::Shopkeeper, NPCType::StatTrainer];
@@ -49,10 +55,15 @@ fn spawn_npcs(
 
     // Zip the positions with NPC types and spawn them
     for (spawn_position, &npc_type) in npc_spawn_positions.iter().zip(npc_types.iter()) {
+        let Some(behavior) = behavior_trees.build(npc_type.behavior()) else {
+            continue;
+        };
+
         spawn_npc(
             &mut commands,
             npc_type,
             *spawn_position,
+            behavior,
             &sprites,
             &sprite_layouts,
             &shadows,
@@ -64,33 +75,27 @@ fn spawn_npc(
     commands: &mut Commands,
     npc_type: NPCType,
     spawn_position: Vec2,
+    behavior: Tree<Behave>,
     sprites: &SpriteAssets,
     sprite_layouts: &SpriteSheetLayouts,
     shadows: &Shadows,
 ) {
+    let npc = base_npc(spawn_position, behavior, shadows);
+
     match npc_type {
         NPCType::Helper => spawn_npc_with_equipment(
             commands,
-            (
-                base_npc(spawn_position, shadows),
-                helper(sprites, sprite_layouts),
-            ),
+            (npc, helper(sprites, sprite_layouts)),
             ice_staff(sprites, sprite_layouts),
         ),
         NPCType::Shopkeeper => spawn_npc_with_equipment(
             commands,
-            (
-                base_npc(spawn_position, shadows),
-                shopkeeper(sprites, sprite_layouts),
-            ),
+            (npc, shopkeeper(sprites, sprite_layouts)),
             axe(sprites),
         ),
         NPCType::StatTrainer => spawn_npc_with_equipment(
             commands,
-            (
-                base_npc(spawn_position, shadows),
-                stat_trainer(sprites, sprite_layouts),
-            ),
+            (npc, stat_trainer(sprites, sprite_layouts)),
             sword(sprites),
         ),
     }
@@ -107,7 +112,7 @@ fn spawn_npc_with_equipment(commands: &mut Commands, npc: impl Bundle, mainhand:
     });
 }
 
-fn base_npc(spawn_position: Vec2, shadows: &Shadows) -> impl Bundle {
+fn base_npc(spawn_position: Vec2, behavior: Tree<Behave>, shadows: &Shadows) -> impl Bundle {
     (
         NPC,
         Anchor::new(spawn_position, WANDER_RADIUS),
@@ -120,9 +125,9 @@ fn base_npc(spawn_position: Vec2, shadows: &Shadows) -> impl Bundle {
                 InteractionZone::NPC,
                 Transform::from_xyz(0.0, CHARACTER_FEET_POS_OFFSET, 0.0),
             ),
-            hurtbox(Vec2::new(26.0, 42.0), GameCollisionLayer::AllyHurtBox),
+            hurtbox(Vec2::new(26.0, 42.0), Faction::Ally),
             physical_collider(),
-            BehaveTree::new(wander_and_retreat_behavior()),
+            BehaveTree::new(behavior),
         ],
     )
 }
@@ -165,17 +170,3 @@ fn stat_trainer(sprites: &SpriteAssets, sprite_layouts: &SpriteSheetLayouts) ->
         observe(interaction::on_stat_trainer_store_open),
     )
 }
-
-fn wander_and_retreat_behavior() -> Tree<Behave> {
-    behave! {
-        Behave::Forever => {
-            Behave::Fallback => {
-                Behave::Sequence => {
-                    Behave::spawn_named("Idle", Idle::default().timer_range(1.0..4.0)),
-                    Behave::spawn_named("Wander", Wander::builder().timer_range(1.0..2.5)),
-                },
-                Behave::spawn_named("Retreat", Retreat),
-            }
-        }
-    }
-}
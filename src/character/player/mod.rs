@@ -6,11 +6,13 @@ mod level;
 mod movement;
 mod overlay;
 mod progression;
+mod stats;
 
 pub mod prelude {
     pub use super::aim::PlayerAim;
     pub use super::interact::*;
     pub use super::progression::GameProgress;
+    pub use super::stats::DerivedStats;
     pub use super::{DisplayableStatType, Player, PlayerStats};
 }
 
@@ -45,6 +47,7 @@ pub(super) fn plugin(app: &mut App) {
         movement::plugin,
         overlay::plugin,
         progression::plugin,
+        stats::plugin,
     ));
 
     // Player spawn
@@ -71,7 +74,10 @@ pub(super) fn plugin(app: &mut App) {
     // Double the mass of npcs/enemies so the player can push them around more
     Mass(100.0),
     IFrames,
-    Purse
+    Footsteps,
+    Faction::Ally,
+    Purse,
+    DerivedStats
 )]
 pub struct Player {
     current_level: u32,
@@ -207,7 +213,7 @@ fn spawn_player(
                 player_aim(gizmo_assets),
                 shadow(&shadows, CHARACTER_FEET_POS_OFFSET - 4.0),
                 physical_collider(),
-                hurtbox(Vec2::new(24.0, 40.0), GameCollisionLayer::AllyHurtBox),
+                hurtbox(Vec2::new(24.0, 40.0), Faction::Ally),
                 (
                     PointLight2d {
                         color: Color::WHITE,
@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        update_derived_stats.in_set(InGameSystems::Simulation),
+    );
+}
+
+const BASE_MOVE_SPEED: f32 = 250.0;
+const MOVE_SPEED_PER_AGILITY: f32 = 0.03;
+const MELEE_DAMAGE_PER_STRENGTH: f32 = 0.1;
+const SPELL_DAMAGE_PER_INTELLECT: f32 = 0.1;
+const BASE_CRIT_CHANCE: f32 = 0.05;
+const CRIT_CHANCE_PER_DEXTERITY: f32 = 0.02;
+const MAX_CRIT_CHANCE: f32 = 0.6;
+const CRIT_MULTIPLIER_PER_DEXTERITY: f32 = 0.02;
+const DROP_RATE_PER_LUCK: f32 = 0.01;
+const BASE_GOLD_DROP_CHANCE: f32 = 0.1;
+
+/// Gameplay values worked out from `PlayerStats`, recomputed whenever the stats change
+#[derive(Component, Clone, Debug)]
+pub struct DerivedStats {
+    pub move_speed: f32,
+    pub melee_damage_multiplier: f32,
+    pub spell_damage_multiplier: f32,
+    pub crit_chance: f32,
+    pub crit_multiplier: f32,
+    /// Applied to each item's `drop_rate` when an enemy is defeated
+    pub drop_rate_multiplier: f32,
+    pub gold_drop_chance: f32,
+}
+
+impl Default for DerivedStats {
+    fn default() -> Self {
+        Self::from(&PlayerStats::default())
+    }
+}
+
+impl From<&PlayerStats> for DerivedStats {
+    fn from(stats: &PlayerStats) -> Self {
+        // Every stat starts at 1, so only points above that add anything
+        let bonus = |stat: u32| stat.saturating_sub(1) as f32;
+
+        DerivedStats {
+            move_speed: BASE_MOVE_SPEED * (1.0 + bonus(stats.agility) * MOVE_SPEED_PER_AGILITY),
+            melee_damage_multiplier: 1.0 + bonus(stats.strength) * MELEE_DAMAGE_PER_STRENGTH,
+            spell_damage_multiplier: 1.0 + bonus(stats.intellect) * SPELL_DAMAGE_PER_INTELLECT,
+            crit_chance: (BASE_CRIT_CHANCE + bonus(stats.dexterity) * CRIT_CHANCE_PER_DEXTERITY)
+                .min(MAX_CRIT_CHANCE),
+            crit_multiplier: BASE_CRIT_MULTIPLIER
+                + bonus(stats.dexterity) * CRIT_MULTIPLIER_PER_DEXTERITY,
+            drop_rate_multiplier: 1.0 + stats.luck as f32 * DROP_RATE_PER_LUCK,
+            gold_drop_chance: BASE_GOLD_DROP_CHANCE + stats.luck as f32 / 100.0,
+        }
+    }
+}
+
+impl DerivedStats {
+    pub fn melee_power(&self) -> AttackPower {
+        AttackPower {
+            damage_multiplier: self.melee_damage_multiplier,
+            crit_chance: self.crit_chance,
+            crit_multiplier: self.crit_multiplier,
+        }
+    }
+
+    pub fn spell_power(&self) -> AttackPower {
+        AttackPower {
+            damage_multiplier: self.spell_damage_multiplier,
+            crit_chance: self.crit_chance,
+            crit_multiplier: self.crit_multiplier,
+        }
+    }
+}
+
+/// Runs when the player spawns and whenever points are spent in the stats shop
+fn update_derived_stats(
+    mut stats_query: Query<
+        (&PlayerStats, &mut DerivedStats, &mut SimpleMotion),
+        Changed<PlayerStats>,
+    >,
+) {
+    for (stats, mut derived_stats, mut motion) in &mut stats_query {
+        *derived_stats = DerivedStats::from(stats);
+        motion.max_speed = derived_stats.move_speed;
+    }
+}
@@ -11,6 +11,8 @@ pub struct SimpleMotion {
     current_speed: f32,
     /// Applied on top of `max_speed`, `slowed_percentage` of 1.0 represents being "stunned"
     slowed_percentage: f32,
+    /// Radians `direction` is turned by to get around obstacles, kept apart so the intended direction never drifts
+    steering_angle: f32,
 }
 
 impl SimpleMotion {
@@ -20,6 +22,7 @@ impl SimpleMotion {
             current_speed: 0.0,
             direction: Vec2::ZERO,
             slowed_percentage: 0.0,
+            steering_angle: 0.0,
         }
     }
 
@@ -47,6 +50,10 @@ impl SimpleMotion {
         self.direction = Vec2::ZERO;
     }
 
+    pub fn steer(&mut self, angle: f32) {
+        self.steering_angle = angle;
+    }
+
     pub fn is_stunned(&self) -> bool {
         self.slowed_percentage >= 1.0
     }
@@ -56,7 +63,8 @@ impl SimpleMotion {
     }
 
     pub fn get_velocity(&self) -> Vec2 {
-        (self.direction * self.current_speed).clamp_length_max(self.max_speed)
+        (Vec2::from_angle(self.steering_angle).rotate(self.direction) * self.current_speed)
+            .clamp_length_max(self.max_speed)
     }
 }
 
@@ -0,0 +1,122 @@
+use std::f32::consts::FRAC_PI_4;
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Angle between the forward whisker and each side whisker
+const WHISKER_ANGLE: f32 = FRAC_PI_4;
+/// Below this fraction of the speed it is trying to move at, a character counts as blocked
+const BLOCKED_SPEED_FRACTION: f32 = 0.1;
+
+/// Lets AI controlled characters feel out obstacles in front of them with short raycast "whiskers"
+/// and steer around them instead of pushing into walls forever
+#[derive(Component)]
+pub struct Steering {
+    whisker_length: f32,
+    /// Whiskers are cast from the physical collider, not the sprite center
+    origin_offset: f32,
+    /// Layers this character can't walk through
+    obstacles: LayerMask,
+    /// How long the character has been trying (and failing) to move, repeats so `Stuck` comes back if a
+    /// behaviour clears it while the character is still blocked
+    blocked_timer: Timer,
+}
+
+impl Default for Steering {
+    fn default() -> Self {
+        Self {
+            whisker_length: 48.0,
+            origin_offset: CHARACTER_FEET_POS_OFFSET,
+            obstacles: LayerMask::from([
+                GameCollisionLayer::HighObstacle,
+                GameCollisionLayer::LowObstacle,
+            ]),
+            blocked_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
+        }
+    }
+}
+
+impl Steering {
+    /// Flying characters only need to steer around walls
+    pub fn flying() -> Self {
+        Self {
+            // Matches `flying_collider`
+            origin_offset: CHARACTER_FEET_POS_OFFSET / 2.0,
+            obstacles: GameCollisionLayer::HighObstacle.into(),
+            ..default()
+        }
+    }
+}
+
+/// Character has been trying to move without going anywhere, behaviours should give up or pick a new direction
+#[derive(Component)]
+pub struct Stuck;
+
+/// Steers the desired direction away from obstacles the whiskers touch, and flags characters that are stuck anyway.
+/// Runs right before motion is turned into velocity, so every behaviour gets it for free
+pub(super) fn steer_around_obstacles(
+    mut commands: Commands,
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+    mut steering_query: Query<(
+        Entity,
+        &mut Steering,
+        &mut SimpleMotion,
+        &Position,
+        &LinearVelocity,
+        Has<Stuck>,
+    )>,
+) {
+    for (entity, mut steering, mut motion, position, velocity, is_stuck) in &mut steering_query {
+        let Ok(forward) = Dir2::new(motion.direction) else {
+            motion.steer(0.0);
+            steering.blocked_timer.reset();
+            continue;
+        };
+
+        if !motion.is_moving() {
+            motion.steer(0.0);
+            steering.blocked_timer.reset();
+            continue;
+        }
+
+        let filter = SpatialQueryFilter::from_mask(steering.obstacles);
+        let origin = position.0 + Vec2::new(0.0, steering.origin_offset);
+        let whisker = |angle: f32| {
+            let direction = Dir2::new_unchecked(Vec2::from_angle(angle).rotate(*forward));
+            spatial_query
+                .cast_ray(origin, direction, steering.whisker_length, true, &filter)
+                .map(|hit| hit.distance)
+        };
+
+        let left = whisker(WHISKER_ANGLE);
+        let center = whisker(0.0);
+        let right = whisker(-WHISKER_ANGLE);
+
+        // Turn toward whichever side has more room, harder the closer the obstacle is
+        let nearest = [left, center, right].into_iter().flatten().reduce(f32::min);
+        let angle = nearest.map_or(0.0, |nearest| {
+            let room_left = left.unwrap_or(steering.whisker_length);
+            let room_right = right.unwrap_or(steering.whisker_length);
+            let turn = if room_left >= room_right { 1.0 } else { -1.0 };
+            let closeness = 1.0 - nearest / steering.whisker_length;
+            turn * WHISKER_ANGLE * (0.25 + closeness).min(1.0)
+        });
+        motion.steer(angle);
+
+        // Whiskers can't catch everything (other characters, corners), so also watch if we actually moved
+        let expected_speed = motion.get_velocity().length();
+        if velocity.length() < expected_speed * BLOCKED_SPEED_FRACTION {
+            if steering.blocked_timer.tick(time.delta()).just_finished() && !is_stuck {
+                commands.entity(entity).insert(Stuck);
+            }
+        } else {
+            steering.blocked_timer.reset();
+            if is_stuck {
+                commands.entity(entity).remove::<Stuck>();
+            }
+        }
+    }
+}
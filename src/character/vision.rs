@@ -1,9 +1,9 @@
-use std::f32::consts::FRAC_PI_4;
+use std::f32::consts::{FRAC_PI_4, FRAC_PI_8};
 
-use avian2d::prelude::{RayCaster, RayHits};
-use bevy::prelude::*;
+use avian2d::prelude::{LinearVelocity, RayCaster, RayHits};
+use bevy::{platform::collections::HashMap, prelude::*};
 
-use crate::prelude::*;
+use crate::{character::Character, prelude::*};
 
 pub(super) fn plugin(app: &mut App) {
     // Vision + Perception
@@ -16,7 +16,12 @@ pub(super) fn plugin(app: &mut App) {
     // Targeting
     app.add_systems(
         Update,
-        (should_target_watched, should_stop_targeting).in_set(InGameSystems::Simulation),
+        (
+            choose_watched_target,
+            should_target_watched,
+            should_stop_targeting,
+        )
+            .in_set(InGameSystems::Simulation),
     )
     .add_observer(on_damage_aggro);
 }
@@ -28,11 +33,30 @@ pub struct Vision {
     pub aim_direction: Vec2,
 }
 
+/// How well an AI leads its shots, from 0.0 (aims wherever the target is standing, with a wide spread)
+/// to 1.0 (fires at the exact intercept point of a moving target)
+#[derive(Component, Clone, Copy)]
+pub struct Accuracy(pub f32);
+
+impl Accuracy {
+    /// Widest random spread (either side of the aim) for the sloppiest shooters, in **radians**
+    const MAX_SPREAD: f32 = FRAC_PI_8;
+
+    pub fn spread(self) -> f32 {
+        Self::MAX_SPREAD * (1.0 - self.0.clamp(0.0, 1.0))
+    }
+
+    /// How much of the target's movement is accounted for when aiming
+    pub fn lead(self) -> f32 {
+        self.0.clamp(0.0, 1.0)
+    }
+}
+
 /// Defines an entity's vision properties, such as how far it can see and how wide its vision cone is.
 /// Immutable component.
 #[derive(Component)]
 #[component(immutable)]
-#[require(Vision, TargetInfo)]
+#[require(Vision, TargetInfo, ThreatTable)]
 pub(super) struct VisionCapabilities {
     /// Half-angle of the entity's field of view, in **radians**.
     ///
@@ -40,16 +64,44 @@ pub(super) struct VisionCapabilities {
     /// - `PI / 4.0` → 45° field on either side (90° total FOV)
     /// - `PI / 2.0` → 90° field on either side (180° total FOV)
     pub vision_cone_radius: f32,
+    /// How far away (in pixels) a hostile entity can be before it is no longer considered worth watching
+    pub sight_range: f32,
 }
 
 impl Default for VisionCapabilities {
     fn default() -> Self {
         Self {
             vision_cone_radius: FRAC_PI_4,
+            sight_range: 350.0,
         }
     }
 }
 
+/// Remembers how much damage each attacker has dealt, so AI can prioritize whoever is hurting them the most.
+/// Threat decays over time so old grudges are eventually forgotten.
+#[derive(Component, Default)]
+pub(super) struct ThreatTable(HashMap<Entity, f32>);
+
+impl ThreatTable {
+    /// Threat lost per second
+    const DECAY_RATE: f32 = 2.0;
+
+    pub fn add(&mut self, attacker: Entity, amount: f32) {
+        *self.0.entry(attacker).or_default() += amount;
+    }
+
+    pub fn get(&self, entity: Entity) -> f32 {
+        self.0.get(&entity).copied().unwrap_or_default()
+    }
+
+    fn decay(&mut self, delta_secs: f32) {
+        self.0.retain(|_, threat| {
+            *threat -= Self::DECAY_RATE * delta_secs;
+            *threat > 0.0
+        });
+    }
+}
+
 /// Stores calculated perception data for a given potential or current target.
 #[derive(Component, Default)]
 pub struct TargetInfo {
@@ -57,6 +109,8 @@ pub struct TargetInfo {
     pub distance: f32,
     /// Direction vector pointing to the observed entity.
     pub direction: Vec2,
+    /// How fast the observed entity is moving, used to lead shots
+    pub velocity: Vec2,
     /// Whether the observed entity is within an unobstructed line of sight (based on `RayCaster`).
     pub line_of_sight: bool,
     /// Whether the observed entity is within the entity’s vision cone angle.
@@ -68,12 +122,12 @@ pub struct TargetInfo {
 /// Added when both line of sight and vision cone are satisfied.
 #[derive(Component)]
 #[relationship(relationship_target = TargetedBy)]
-pub(super) struct Targeting(pub Entity);
+pub struct Targeting(pub Entity);
 
 /// Inverse of `Targeting` — tracked by the targeted entity.
 #[derive(Component)]
 #[relationship_target(relationship = Targeting)]
-pub(super) struct TargetedBy(Vec<Entity>);
+pub struct TargetedBy(Vec<Entity>);
 
 /// Tracks which entity the NPC is currently watching or trying to detect.
 #[derive(Component)]
@@ -96,22 +150,33 @@ struct TargetLock;
 
 /// Updates the `Vision` component's direction for each entity:
 /// - If the entity is actively targeting something, aim at it.
+/// - If the entity heard a noise, turn toward where it came from.
 /// - Otherwise, aim in the direction it is facing.
 fn update_aim_position(
     mut character_query: Query<
-        (&mut Vision, &TargetInfo, Has<Targeting>, &FacingDirection),
+        (
+            &mut Vision,
+            &TargetInfo,
+            Has<Targeting>,
+            Option<&HeardNoise>,
+            &FacingDirection,
+            &Transform,
+        ),
         Without<Player>,
     >,
 ) {
-    character_query
-        .par_iter_mut()
-        .for_each(|(mut vision, target_info, has_target, facing_dir)| {
+    character_query.par_iter_mut().for_each(
+        |(mut vision, target_info, has_target, heard_noise, facing_dir, transform)| {
             vision.aim_direction = if has_target {
                 target_info.direction
+            } else if let Some(heard_noise) = heard_noise {
+                (heard_noise.position - transform.translation.xy())
+                    .normalize_or(facing_dir.to_vec2())
             } else {
                 facing_dir.to_vec2()
             };
-        });
+        },
+    );
 }
 
 /// Updates the direction and distance of the watched (or targeted) entity,
@@ -127,14 +192,14 @@ fn update_target_info(
         ),
         Without<Player>,
     >,
-    target_query: Query<&Transform>,
+    target_query: Query<(&Transform, Option<&LinearVelocity>)>,
 ) {
     npc_query.par_iter_mut().for_each(
         |(mut target_info, mut ray_caster, transform, watching, targeting)| {
             // Track distance and direction to target if there is one, otherwise track watching
             let target_entity = targeting.map_or(watching.0, |t| t.0);
 
-            if let Ok(target_transform) = target_query.get(target_entity) {
+            if let Ok((target_transform, target_velocity)) = target_query.get(target_entity) {
                 let target_direction = (target_transform.translation.xy()
                     - transform.translation.xy())
                 .normalize_or_zero();
@@ -146,6 +211,7 @@ fn update_target_info(
 
                 target_info.direction = target_direction;
                 target_info.distance = target_distance;
+                target_info.velocity = target_velocity.map_or(Vec2::ZERO, |v| v.0);
 
                 ray_caster.direction = Dir2::new(target_direction).unwrap_or(Dir2::X);
             }
@@ -208,28 +274,102 @@ fn is_target_in_sight(
 // AGGRO / TARGETING
 // ---------------------
 
+/// How much a single point of threat is worth compared to being right next to the candidate
+const THREAT_WEIGHT: f32 = 0.1;
+
+/// Picks which entity is most worth watching out of every hostile character within sight range.
+/// Closer candidates and candidates that have dealt more damage (threat) are preferred.
+///
+/// Entities that are already targeting something stay locked onto it until they lose sight of it.
+fn choose_watched_target(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut watcher_query: Query<(
+        Entity,
+        &Faction,
+        &Transform,
+        &VisionCapabilities,
+        &mut ThreatTable,
+        Option<&Watching>,
+        Has<Targeting>,
+    )>,
+    candidate_query: Query<(Entity, &Faction, &Transform, &Health), With<Character>>,
+) {
+    for (entity, faction, transform, capabilities, mut threat_table, watching, has_target) in
+        &mut watcher_query
+    {
+        threat_table.decay(time.delta_secs());
+
+        if has_target {
+            continue;
+        }
+
+        let position = transform.translation.xy();
+
+        let best_candidate = candidate_query
+            .iter()
+            .filter(|(candidate, candidate_faction, _, health)| {
+                *candidate != entity
+                    && faction.is_hostile_to(**candidate_faction)
+                    && !health.is_dead()
+            })
+            .filter_map(|(candidate, _, candidate_transform, _)| {
+                let distance = position.distance(candidate_transform.translation.xy());
+
+                (distance <= capabilities.sight_range).then(|| {
+                    let proximity = 1.0 - (distance / capabilities.sight_range);
+                    let score = proximity + threat_table.get(candidate) * THREAT_WEIGHT;
+                    (candidate, score)
+                })
+            })
+            .max_by(|(_, score_a), (_, score_b)| score_a.total_cmp(score_b))
+            .map(|(candidate, _)| candidate);
+
+        match (best_candidate, watching) {
+            (Some(candidate), Some(watching)) if candidate == watching.0 => {}
+            (Some(candidate), _) => {
+                commands.entity(entity).insert(Watching(candidate));
+            }
+            (None, Some(_)) => {
+                commands.entity(entity).remove::<Watching>();
+            }
+            (None, None) => {}
+        }
+    }
+}
+
 /// Handles auto-targeting when an entity is attacked.
 /// Ignores line of sight or cone checks — instant rage response.
-/// For now, this will just target the entity the AI is watching, not necessarily the entity that damaged them.
-/// TODO: Refactor projectiles/melee/damage to hold the "original source" entity so this can be improved
+///
+/// Retaliates against the character behind the hit, even if it came from a projectile or status effect.
+/// Damage with no instigator (like the environment) falls back to the watched entity
 fn on_damage_aggro(
     damage_dealt: On<DamageDealt>,
     mut commands: Commands,
-    target_query: Query<&Watching>,
+    mut target_query: Query<(&mut ThreatTable, Option<&Watching>)>,
 ) {
     let damaged_entity = damage_dealt.entity;
 
-    if let Ok(watching) = target_query.get(damaged_entity) {
-        debug!(
-            "I've been hit: {}, attacking: {}",
-            damaged_entity, watching.0
-        );
-        commands
-            .entity(damaged_entity)
-            .insert((TargetLock, Targeting(watching.0)));
+    let Ok((mut threat_table, watching)) = target_query.get_mut(damaged_entity) else {
+        return;
+    };
 
-        schedule_component_removal::<TargetLock>(&mut commands, damaged_entity, 6.0);
-    }
+    let attacker = damage_dealt
+        .instigator
+        .or(watching.map(|watching| watching.0));
+
+    let Some(attacker) = attacker.filter(|&attacker| attacker != damaged_entity) else {
+        return;
+    };
+
+    debug!("I've been hit: {}, attacking: {}", damaged_entity, attacker);
+    threat_table.add(attacker, damage_dealt.damage);
+
+    commands
+        .entity(damaged_entity)
+        .insert((TargetLock, Watching(attacker), Targeting(attacker)));
+
+    schedule_component_removal::<TargetLock>(&mut commands, damaged_entity, 6.0);
 }
 
 /// Starts targeting the watched entity if it is in sight and in the vision cone.
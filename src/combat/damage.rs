@@ -1,50 +1,21 @@
+use std::collections::HashMap;
+
 use avian2d::prelude::*;
 
 use bevy::prelude::*;
 use rand::Rng;
+use serde::Deserialize;
 
 use crate::{
     combat::{
         health::Health,
-        invulnerable::IFrames,
+        invulnerable::{IFrames, Invulnerable},
+        mitigation::Mitigation,
         status_effects::{ApplyEffects, Effects},
     },
-    prelude::{GameCollisionLayer, Player},
+    prelude::{Faction, GameCollisionLayer, Player},
 };
 
-#[derive(PartialEq, Clone, Copy, Debug)]
-pub enum DamageSource {
-    Player,
-    Enemy,
-    NPC,
-    Environment,
-}
-
-impl From<bool> for DamageSource {
-    fn from(is_enemy: bool) -> Self {
-        if is_enemy {
-            DamageSource::Enemy
-        } else {
-            DamageSource::Player
-        }
-    }
-}
-
-impl From<DamageSource> for LayerMask {
-    fn from(source: DamageSource) -> Self {
-        match source {
-            DamageSource::Player | DamageSource::NPC => GameCollisionLayer::EnemyHurtBox.to_bits(),
-            DamageSource::Enemy => GameCollisionLayer::AllyHurtBox.to_bits(),
-            DamageSource::Environment => {
-                // Environment can affect all characters
-                GameCollisionLayer::AllyHurtBox.to_bits()
-                    | GameCollisionLayer::EnemyHurtBox.to_bits()
-            }
-        }
-        .into()
-    }
-}
-
 #[derive(Copy, Clone)]
 pub enum Damage {
     Single(f32),
@@ -58,6 +29,13 @@ impl Damage {
             Damage::Single(amount) => amount,
         }
     }
+
+    pub fn scaled(self, multiplier: f32) -> Self {
+        match self {
+            Damage::Range((min, max)) => Damage::Range((min * multiplier, max * multiplier)),
+            Damage::Single(amount) => Damage::Single(amount * multiplier),
+        }
+    }
 }
 
 impl Default for Damage {
@@ -66,16 +44,82 @@ impl Default for Damage {
     }
 }
 
+/// What kind of harm a hit does, so characters can resist some kinds more than others
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize)]
+pub enum DamageType {
+    #[default]
+    Physical,
+    Fire,
+    Ice,
+    Arcane,
+    /// Ignores all resistances
+    True,
+}
+
+/// Fraction of each damage type a character shrugs off. 1.0 is immune, negative values are weaknesses
+#[derive(Component, Clone, Debug, Default, Deserialize)]
+pub struct Resistances(pub HashMap<DamageType, f32>);
+
+impl Resistances {
+    pub fn damage_multiplier(&self, damage_type: DamageType) -> f32 {
+        if damage_type == DamageType::True {
+            return 1.0;
+        }
+
+        1.0 - self.0.get(&damage_type).copied().unwrap_or(0.0).min(1.0)
+    }
+}
+
+/// Damage multiplier for critical hits when nothing improves on it, like a character's dexterity
+pub const BASE_CRIT_MULTIPLIER: f32 = 1.5;
+/// Critical hits knock their target back this much harder
+const CRIT_KNOCKBACK_MULTIPLIER: f32 = 2.0;
+
+/// Extra chance from 0.0 to 1.0 for a weapon to land critical hits, on top of its holder's stats
+#[derive(Component, Clone, Copy)]
+pub struct CritChance(pub f32);
+
+/// How hard an attacker hits, stamped onto projectiles when they are fired so the caster's stats travel with them
+#[derive(Component, Clone, Copy, Debug)]
+pub struct AttackPower {
+    pub damage_multiplier: f32,
+    pub crit_chance: f32,
+    pub crit_multiplier: f32,
+}
+
+impl Default for AttackPower {
+    fn default() -> Self {
+        Self {
+            damage_multiplier: 1.0,
+            crit_chance: 0.0,
+            crit_multiplier: BASE_CRIT_MULTIPLIER,
+        }
+    }
+}
+
+impl AttackPower {
+    /// Adds the crit chance of the weapon doing the attacking
+    pub fn with_weapon(mut self, crit_chance: Option<&CritChance>) -> Self {
+        self.crit_chance += crit_chance.map_or(0.0, |crit_chance| crit_chance.0);
+        self
+    }
+}
+
+/// Character behind a projectile or status effect, so the damage it deals is credited to them
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Instigator(pub Entity);
+
 #[derive(Component)]
 #[require(Sensor)]
 pub struct HurtBox;
 
-pub fn hurtbox(size: Vec2, membership: GameCollisionLayer) -> impl Bundle {
+/// Hurtbox membership comes from the faction, so only hostile hitboxes can collide with it
+pub fn hurtbox(size: Vec2, faction: Faction) -> impl Bundle {
     (
         HurtBox,
         Collider::rectangle(size.x, size.y),
         Transform::from_xyz(0.0, -8.0, 0.0),
-        CollisionLayers::new(membership, [GameCollisionLayer::HitBox]),
+        CollisionLayers::new(faction.hurtbox_layer(), [GameCollisionLayer::HitBox]),
     )
 }
 
@@ -86,10 +130,16 @@ pub struct AttemptDamage {
     pub ignore_invulnerable: bool,
     /// We treat damage as a range with RNG determining which value is dealt
     pub damage: Damage,
+    pub damage_type: DamageType,
     /// Not all damage has a "Source" entity, like environmental damage or damage-over-time effects
     pub damage_source: Option<Entity>,
+    /// Character who started the attack, kept through projectiles and status effects. `None` for environmental damage
+    pub instigator: Option<Entity>,
     /// damage direction, ex. velocity direction of projectile or character position for melee
     pub direction: Option<Vec2>,
+    /// Chance from 0.0 to 1.0 that the hit is critical and multiplied by `crit_multiplier`
+    pub crit_chance: f32,
+    pub crit_multiplier: f32,
 }
 
 impl Default for AttemptDamage {
@@ -98,8 +148,12 @@ impl Default for AttemptDamage {
             entity: Entity::PLACEHOLDER,
             ignore_invulnerable: false,
             damage: Damage::Single(1.0),
+            damage_type: DamageType::Physical,
             damage_source: None,
+            instigator: None,
             direction: None,
+            crit_chance: 0.0,
+            crit_multiplier: BASE_CRIT_MULTIPLIER,
         }
     }
 }
@@ -109,22 +163,30 @@ impl Default for AttemptDamage {
 #[derive(EntityEvent)]
 pub struct DamageDealt {
     pub entity: Entity,
+    /// Damage rolled by the attacker, before any `MitigationStage`
+    pub raw_damage: f32,
+    /// Damage actually taken off the entity's health after mitigation
     pub damage: f32,
     pub damage_source: Option<Entity>,
+    pub instigator: Option<Entity>,
     /// damage direction, ex. velocity direction of projectile or character position for melee
     pub direction: Option<Vec2>,
+    pub is_critical: bool,
 }
 
 #[derive(EntityEvent)]
 pub struct Defeated {
     pub entity: Entity,
+    /// Character credited with the kill
+    pub instigator: Option<Entity>,
 }
 
 pub(super) fn on_damage_event(
     attempt_damage: On<AttemptDamage>,
     mut commands: Commands,
     hurt_box_query: Query<&ChildOf, With<HurtBox>>,
-    mut damaged_query: Query<(&mut Health, Option<&mut IFrames>)>,
+    mut damaged_query: Query<(&mut Health, Option<&mut IFrames>), Without<Invulnerable>>,
+    mitigation: Mitigation,
     source_query: Query<&Effects>,
 ) {
     // Damage can be applied to an entities hurtbox, or to the entity directly
@@ -151,26 +213,40 @@ pub(super) fn on_damage_event(
         }
 
         // Convert `Damage` to raw damage amount
-        let damage = attempt_damage.damage.to_float();
+        let mut raw_damage = attempt_damage.damage.to_float();
+        let is_critical =
+            rand::rng().random_bool(f64::from(attempt_damage.crit_chance.clamp(0.0, 1.0)));
+        if is_critical {
+            raw_damage *= attempt_damage.crit_multiplier;
+        }
+
+        let damage = mitigation.apply(damaged_entity, raw_damage, attempt_damage.damage_type);
         health.take_damage(damage);
 
         // Because AttemptDamageEvent may not result in damage being applied (invulnerable or entity without health)
         // we send this event for guranteed "X damage has been done". Proper change detection added to bevy would mean this isn't needed
         commands.trigger(DamageDealt {
             entity: damaged_entity,
+            raw_damage,
             damage,
             damage_source: attempt_damage.damage_source,
+            instigator: attempt_damage.instigator,
             direction: attempt_damage.direction,
+            is_critical,
         });
 
         if health.hp == 0.0 {
             commands.trigger(Defeated {
                 entity: damaged_entity,
+                instigator: attempt_damage.instigator,
             });
         } else if let Some(source_entity) = attempt_damage.damage_source {
             // If entity is still alive and damage source exists and has effects list, we apply status effects
             if let Ok(effects) = source_query.get(source_entity) {
-                commands.trigger(ApplyEffects::new(effects, damaged_entity));
+                commands.trigger(
+                    ApplyEffects::new(effects, damaged_entity)
+                        .with_instigator(attempt_damage.instigator),
+                );
             }
         }
     }
@@ -204,15 +280,19 @@ pub(super) fn on_damage_dealt_flash(
     }
 }
 
+/// Color a sprite returns to once a damage flash ends, for entities that aren't drawn with their plain colors
+#[derive(Component, Clone, Copy)]
+pub struct Tint(pub Color);
+
 pub(super) fn tick_and_remove_damage_flash(
-    mut damage_flash_query: Query<(&mut DamageFlash, &mut Sprite)>,
+    mut damage_flash_query: Query<(&mut DamageFlash, &mut Sprite, Option<&Tint>)>,
     time: Res<Time>,
 ) {
     damage_flash_query
         .par_iter_mut()
-        .for_each(|(mut flash, mut sprite)| {
+        .for_each(|(mut flash, mut sprite, tint)| {
             if flash.0.tick(time.delta()).is_finished() {
-                sprite.color = Color::WHITE;
+                sprite.color = tint.map_or(Color::WHITE, |tint| tint.0);
             }
         });
 }
@@ -229,9 +309,15 @@ pub(super) fn on_damage_dealt_knockback(
         && let Ok(knockback) = knockback_query.get(damage_source)
         && let Some(damage_direction) = damage_dealt.direction
     {
+        let strength = if damage_dealt.is_critical {
+            knockback.0 * CRIT_KNOCKBACK_MULTIPLIER
+        } else {
+            knockback.0
+        };
+
         forces
             .get_mut(damage_dealt.entity)?
-            .apply_force(damage_direction * knockback.0 * 1_000_000.0);
+            .apply_force(damage_direction * strength * 1_000_000.0);
     }
     Ok(())
 }
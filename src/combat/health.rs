@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::combat::status_effects::Statuses;
+
 #[derive(EntityEvent)]
 pub struct AttemptHeal {
     pub entity: Entity,
@@ -12,6 +14,10 @@ pub struct Healed {
     pub amount: f32,
 }
 
+/// Put on a status to cut the healing its target receives, 1.0 blocks healing entirely
+#[derive(Component, Clone, Copy)]
+pub struct HealingReduction(pub f32);
+
 #[derive(Component)]
 pub struct Health {
     pub hp: f32,
@@ -27,6 +33,11 @@ impl Health {
         self.hp = (self.hp - amount).max(0.0);
     }
 
+    /// Remaining health from 0.0 to 1.0
+    pub fn fraction(&self) -> f32 {
+        self.hp / self.max_hp
+    }
+
     pub fn is_dead(&self) -> bool {
         self.hp <= 0.0
     }
@@ -53,10 +64,18 @@ impl Default for Health {
 pub(super) fn on_healing_event(
     attempt_heal: On<AttemptHeal>,
     mut commands: Commands,
-    mut healed_query: Query<&mut Health>,
+    mut healed_query: Query<(&mut Health, Option<&Statuses>)>,
+    reduction_query: Query<&HealingReduction>,
 ) {
-    if let Ok(mut health) = healed_query.get_mut(attempt_heal.entity) {
-        let actual_amount = health.add_health(attempt_heal.amount);
+    if let Ok((mut health, statuses)) = healed_query.get_mut(attempt_heal.entity) {
+        let reduction: f32 = statuses.map_or(0.0, |statuses| {
+            reduction_query
+                .iter_many(statuses.iter())
+                .map(|reduction| reduction.0)
+                .sum()
+        });
+
+        let actual_amount = health.add_health(attempt_heal.amount * (1.0 - reduction.min(1.0)));
         commands.trigger(Healed {
             entity: attempt_heal.entity,
             amount: actual_amount,
@@ -2,6 +2,10 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 
+/// Never takes damage from any source, for enemies that can only be escaped
+#[derive(Component)]
+pub struct Invulnerable;
+
 /// Component to mark whether an entity has iframes when hit
 #[derive(Component)]
 pub struct IFrames {
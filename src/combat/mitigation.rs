@@ -0,0 +1,149 @@
+use std::iter;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::{
+    combat::{
+        damage::{DamageType, Resistances},
+        status_effects::Statuses,
+    },
+    prelude::{Equipped, Items},
+};
+
+/// Armor needed to halve incoming damage. Each point is worth a little less than the last
+const ARMOR_FOR_HALF_DAMAGE: f32 = 100.0;
+
+/// Steps a hit goes through between being rolled and reaching `Health`, in the order they are applied.
+/// Each stage runs its built in defense, then every `MitigationModifier` hooked into it.
+/// "True" damage skips every stage
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MitigationStage {
+    /// A raised shield soaks up part of the hit
+    Block,
+    /// Typed damage the character is resistant or weak to
+    Resistance,
+    /// Percentage reduction from armor with diminishing returns
+    Armor,
+    /// Flat amount taken off whatever is left
+    FlatReduction,
+}
+
+impl MitigationStage {
+    pub const ORDER: [Self; 4] = [
+        Self::Block,
+        Self::Resistance,
+        Self::Armor,
+        Self::FlatReduction,
+    ];
+}
+
+/// Armor from a character, their equipped items and their statuses is added together
+#[derive(Component, Clone, Copy, Default)]
+pub struct Armor(pub f32);
+
+/// Flat damage taken off every hit, from a character, their equipped items and their statuses
+#[derive(Component, Clone, Copy, Default)]
+pub struct DamageReduction(pub f32);
+
+/// Fraction of damage blocked, inserted on a character while they hold up a shield
+#[derive(Component, Clone, Copy)]
+pub struct Blocking(pub f32);
+
+/// Hooks extra mitigation into a `MitigationStage` without touching the pipeline itself.
+/// Picked up from the defender, their equipped items and their statuses, like `Armor`
+#[derive(Component, Clone, Copy)]
+pub struct MitigationModifier {
+    pub stage: MitigationStage,
+    /// Damage is multiplied by this first, 1.0 leaves it as is
+    pub multiplier: f32,
+    /// Then this much is taken off
+    pub flat: f32,
+}
+
+impl MitigationModifier {
+    fn apply(self, damage: f32) -> f32 {
+        (damage * self.multiplier.max(0.0) - self.flat).max(0.0)
+    }
+}
+
+/// Reduction from `armor` as a fraction from 0.0 to just under 1.0
+pub fn armor_reduction(armor: f32) -> f32 {
+    let armor = armor.max(0.0);
+    armor / (armor + ARMOR_FOR_HALF_DAMAGE)
+}
+
+/// Everything needed to work out how much of a hit a character shrugs off
+#[derive(SystemParam)]
+pub struct Mitigation<'w, 's> {
+    defenders: Query<
+        'w,
+        's,
+        (
+            Option<&'static Blocking>,
+            Option<&'static Resistances>,
+            Option<&'static Items>,
+            Option<&'static Statuses>,
+        ),
+    >,
+    armor: Query<'w, 's, &'static Armor>,
+    reductions: Query<'w, 's, &'static DamageReduction>,
+    modifiers: Query<'w, 's, &'static MitigationModifier>,
+    equipped: Query<'w, 's, (), With<Equipped>>,
+}
+
+impl Mitigation<'_, '_> {
+    /// Runs `raw_damage` through every `MitigationStage`, returning what is left for `Health`
+    pub fn apply(&self, defender: Entity, raw_damage: f32, damage_type: DamageType) -> f32 {
+        if damage_type == DamageType::True {
+            return raw_damage;
+        }
+
+        let Ok((blocking, resistances, items, statuses)) = self.defenders.get(defender) else {
+            return raw_damage;
+        };
+
+        // The defender, what they have equipped and whatever buffs are on them can all carry defenses
+        let sources: Vec<Entity> = iter::once(defender)
+            .chain(
+                items
+                    .into_iter()
+                    .flat_map(RelationshipTarget::iter)
+                    .filter(|item| self.equipped.contains(*item)),
+            )
+            .chain(statuses.into_iter().flat_map(RelationshipTarget::iter))
+            .collect();
+
+        MitigationStage::ORDER
+            .into_iter()
+            .fold(raw_damage, |damage, stage| {
+                let damage = match stage {
+                    MitigationStage::Block => {
+                        damage * (1.0 - blocking.map_or(0.0, |blocking| blocking.0.clamp(0.0, 1.0)))
+                    }
+                    MitigationStage::Resistance => {
+                        damage
+                            * resistances.map_or(1.0, |resistances| {
+                                resistances.damage_multiplier(damage_type)
+                            })
+                    }
+                    MitigationStage::Armor => {
+                        let armor: f32 = self.armor.iter_many(&sources).map(|armor| armor.0).sum();
+                        damage * (1.0 - armor_reduction(armor))
+                    }
+                    MitigationStage::FlatReduction => {
+                        let reduction: f32 = self
+                            .reductions
+                            .iter_many(&sources)
+                            .map(|reduction| reduction.0)
+                            .sum();
+                        (damage - reduction).max(0.0)
+                    }
+                };
+
+                self.modifiers
+                    .iter_many(&sources)
+                    .filter(|modifier| modifier.stage == stage)
+                    .fold(damage, |damage, modifier| modifier.apply(damage))
+            })
+    }
+}
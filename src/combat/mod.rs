@@ -2,6 +2,7 @@ mod damage;
 mod health;
 mod invulnerable;
 mod mana;
+mod mitigation;
 mod projectile;
 mod status_effects;
 
@@ -14,6 +15,7 @@ pub mod prelude {
     pub use super::health::*;
     pub use super::invulnerable::*;
     pub use super::mana::*;
+    pub use super::mitigation::*;
     pub use super::projectile::*;
     pub use super::status_effects::prelude::*;
 }
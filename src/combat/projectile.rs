@@ -7,7 +7,9 @@ use bevy::{
 use bevy_lit::prelude::PointLight2d;
 
 use crate::{
-    combat::damage::{AttemptDamage, Damage, HurtBox, Knockback},
+    combat::damage::{
+        AttackPower, AttemptDamage, Damage, DamageType, HurtBox, Instigator, Knockback,
+    },
     prelude::*,
 };
 
@@ -26,10 +28,12 @@ pub(super) fn plugin(app: &mut App) {
     CollidingEntities,
     AnimationIndices::Cycle((0..=4).cycle()),
     AnimationTimer(Timer::from_seconds(0.2, TimerMode::Repeating)),
+    AttackPower,
     Disabled
 )]
 pub struct Projectile {
     pub damage: Damage,
+    pub damage_type: DamageType,
     pub speed: f32,
     angle_offset: f32,
     spawn_offset: f32,
@@ -39,6 +43,7 @@ impl Default for Projectile {
     fn default() -> Self {
         Self {
             damage: Damage::Range((5.0, 10.0)),
+            damage_type: DamageType::Physical,
             speed: 600.0,
             angle_offset: 0.0,
             spawn_offset: 0.0,
@@ -62,6 +67,7 @@ pub fn fireball(
     (
         Projectile {
             damage: Damage::Single(3.0),
+            damage_type: DamageType::Fire,
             speed: 450.0,
             spawn_offset: 20.0,
             angle_offset,
@@ -83,7 +89,7 @@ pub fn fireball(
         Collider::circle(10.0),
         AnimationTimer(Timer::from_seconds(0.042, TimerMode::Repeating)),
         Knockback(5.0),
-        related!(Effects[(Burning::default(), Lifespan::new(2.5))]),
+        related!(Effects[(Burning, Lifespan::new(2.5))]),
     )
 }
 
@@ -95,6 +101,7 @@ pub fn icebolt(
     (
         Projectile {
             damage: Damage::Range((10.0, 20.0)),
+            damage_type: DamageType::Ice,
             speed: 350.0,
             spawn_offset: 26.0,
             angle_offset,
@@ -123,20 +130,33 @@ pub fn icebolt(
 
 fn handle_collisions(
     mut commands: Commands,
-    projectile_query: Query<(&Projectile, &LinearVelocity, &CollidingEntities, Entity)>,
+    projectile_query: Query<(
+        &Projectile,
+        &AttackPower,
+        &LinearVelocity,
+        &CollidingEntities,
+        Option<&Instigator>,
+        Entity,
+    )>,
     hurt_box_query: Query<&HurtBox>,
     reflector_query: Query<&ProjectileReflection>,
 ) {
-    for (projectile, velocity, colliding_entities, projectile_entity) in projectile_query.iter() {
+    for (projectile, power, velocity, colliding_entities, instigator, projectile_entity) in
+        projectile_query.iter()
+    {
         // ignore further collisions after ANY collision with the projectile
         if let Some(&colliding_entity) = colliding_entities.iter().next() {
             // If the thing we collide with has a HurtBox, lets try to damage it!
             if hurt_box_query.contains(colliding_entity) {
                 commands.trigger(AttemptDamage {
                     entity: colliding_entity,
-                    damage: projectile.damage,
+                    damage: projectile.damage.scaled(power.damage_multiplier),
+                    damage_type: projectile.damage_type,
                     damage_source: Some(projectile_entity),
+                    instigator: instigator.map(|instigator| instigator.0),
                     direction: Some(velocity.normalize()),
+                    crit_chance: power.crit_chance,
+                    crit_multiplier: power.crit_multiplier,
                     ..default()
                 });
             }
@@ -152,24 +172,65 @@ fn handle_collisions(
 pub struct FireProjectile {
     #[event_target]
     projectile: Entity,
-    damage_source: DamageSource,
+    /// Faction of whoever fired the projectile, determines who it can hurt
+    faction: Faction,
     position: Vec2,
     aim_direction: Vec2,
+    /// Stats of whoever fired the projectile, carried by the projectile until it hits
+    power: AttackPower,
+    /// Character who fired the projectile, credited with whatever it hits
+    instigator: Option<Entity>,
 }
 
-impl From<(Entity, DamageSource, Vec2, Vec2)> for FireProjectile {
-    fn from(
-        (projectile, damage_source, position, aim_direction): (Entity, DamageSource, Vec2, Vec2),
-    ) -> Self {
+impl FireProjectile {
+    pub fn with_power(mut self, power: AttackPower) -> Self {
+        self.power = power;
+        self
+    }
+
+    pub fn with_instigator(mut self, instigator: Entity) -> Self {
+        self.instigator = Some(instigator);
+        self
+    }
+}
+
+impl From<(Entity, Faction, Vec2, Vec2)> for FireProjectile {
+    fn from((projectile, faction, position, aim_direction): (Entity, Faction, Vec2, Vec2)) -> Self {
         FireProjectile {
             projectile,
-            damage_source,
+            faction,
             position,
             aim_direction: aim_direction.normalize(),
+            power: AttackPower::default(),
+            instigator: None,
         }
     }
 }
 
+/// Seconds until a projectile fired at `speed` from the origin can meet a target at `offset` moving with
+/// `target_velocity`, or `None` if the target is outrunning the projectile
+pub fn intercept_time(offset: Vec2, target_velocity: Vec2, speed: f32) -> Option<f32> {
+    // |offset + target_velocity * t| = speed * t, solved for the smallest positive t
+    let a = target_velocity.length_squared() - speed * speed;
+    let b = 2.0 * offset.dot(target_velocity);
+    let c = offset.length_squared();
+
+    if a.abs() < f32::EPSILON {
+        return (b < 0.0).then(|| -c / b);
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let root = discriminant.sqrt();
+    [(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)]
+        .into_iter()
+        .filter(|t| *t > 0.0)
+        .reduce(f32::min)
+}
+
 fn on_fire_projectile(
     fire: On<FireProjectile>,
     mut commands: Commands,
@@ -184,7 +245,7 @@ fn on_fire_projectile(
 
     let position = fire.position + (projectile_direction * projectile.spawn_offset);
 
-    commands
+    let fired = commands
         .entity(fire.projectile)
         .clone_and_spawn_with_opt_out(|builder| {
             builder.linked_cloning(true);
@@ -199,9 +260,17 @@ fn on_fire_projectile(
                 ..default()
             },
             LinearVelocity(projectile_direction * projectile.speed),
+            fire.power,
             CollisionLayers::new(
                 GameCollisionLayer::PROJECTILE_MEMBERSHIPS,
-                LayerMask::from(fire.damage_source) | GameCollisionLayer::HighObstacle,
+                fire.faction.hostile_layers()
+                    | LayerMask::from(GameCollisionLayer::PROJECTILE_FILTERS),
             ),
-        ));
+        ))
+        .id();
+
+    // The caster is remembered so whatever the projectile hits can be traced back to them
+    if let Some(instigator) = fire.instigator {
+        commands.entity(fired).insert(Instigator(instigator));
+    }
 }
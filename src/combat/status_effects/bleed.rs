@@ -0,0 +1,29 @@
+use avian2d::prelude::LinearVelocity;
+use bevy::prelude::*;
+
+use crate::combat::{
+    damage::DamageType,
+    status_effects::{StatusOf, StatusType, damage_over_time::DamageOverTime},
+};
+
+/// Bleeding ticks this many times faster while the target is on the move
+const MOVING_TICK_SPEED: f32 = 3.0;
+/// Slower than this counts as standing still
+const MOVING_SPEED_THRESHOLD: f32 = 10.0;
+
+#[derive(Component, Clone, Default)]
+#[require(StatusType::Bleed, DamageOverTime::new(1.5, DamageType::Physical, 1.0))]
+pub struct Bleeding;
+
+pub(super) fn bleed_faster_while_moving(
+    mut status_query: Query<(&mut DamageOverTime, &StatusOf), With<Bleeding>>,
+    velocity_query: Query<&LinearVelocity>,
+) {
+    for (mut dot, status_of) in &mut status_query {
+        let is_moving = velocity_query
+            .get(status_of.0)
+            .is_ok_and(|velocity| velocity.length() > MOVING_SPEED_THRESHOLD);
+
+        dot.tick_speed = if is_moving { MOVING_TICK_SPEED } else { 1.0 };
+    }
+}
@@ -0,0 +1,157 @@
+use bevy::{color::palettes::tailwind::YELLOW_300, prelude::*};
+use bevy_lit::prelude::PointLight2d;
+
+use crate::{
+    combat::{
+        damage::{AttemptDamage, Damage, DamageType, Instigator},
+        status_effects::{Stacks, StatusApplied, StatusOf, StatusVfxOf},
+    },
+    prelude::*,
+};
+
+/// Hurts whoever the status is on every time `tick` finishes. Burning, poison and bleeding are all built on this
+#[derive(Component, Clone)]
+pub struct DamageOverTime {
+    pub damage: f32,
+    pub damage_type: DamageType,
+    tick: Timer,
+    /// How fast `tick` runs, statuses like bleeding speed it up depending on what the target is doing
+    pub tick_speed: f32,
+    /// `None` for statuses without art of their own yet
+    pub visual: Option<DotVisual>,
+}
+
+impl DamageOverTime {
+    pub fn new(damage: f32, damage_type: DamageType, tick_rate: f32) -> Self {
+        Self {
+            damage,
+            damage_type,
+            tick: Timer::from_seconds(tick_rate, TimerMode::Repeating),
+            tick_speed: 1.0,
+            visual: None,
+        }
+    }
+
+    pub fn with_visual(mut self, visual: DotVisual) -> Self {
+        self.visual = Some(visual);
+        self
+    }
+}
+
+/// Effect drawn on a character while a `DamageOverTime` status is on them
+#[derive(Clone, Copy, Debug)]
+pub enum DotVisual {
+    Flames,
+}
+
+impl DotVisual {
+    fn light(self) -> Color {
+        match self {
+            DotVisual::Flames => Color::from(YELLOW_300),
+        }
+    }
+
+    fn scale(self) -> f32 {
+        match self {
+            DotVisual::Flames => 1.2,
+        }
+    }
+}
+
+pub(super) fn apply_damage_over_time(
+    mut commands: Commands,
+    status_query: Query<(Entity, &StatusOf, &DamageOverTime), Without<StatusApplied>>,
+    sprites: Res<SpriteAssets>,
+    sprite_layouts: Res<SpriteSheetLayouts>,
+) {
+    status_query.iter().for_each(|(status, status_of, dot)| {
+        commands.entity(status).insert(StatusApplied);
+
+        if let Some(visual) = dot.visual {
+            commands.entity(status_of.0).with_child(dot_vfx(
+                &sprites,
+                &sprite_layouts,
+                visual,
+                status,
+            ));
+        }
+    });
+}
+
+pub(super) fn tick_damage_over_time(
+    mut dot_query: Query<&mut DamageOverTime, With<StatusApplied>>,
+    time: Res<Time>,
+) {
+    for mut dot in &mut dot_query {
+        let delta = time.delta().mul_f32(dot.tick_speed.max(0.0));
+        dot.tick.tick(delta);
+    }
+}
+
+pub(super) fn deal_damage_over_time(
+    mut commands: Commands,
+    status_query: Query<
+        (
+            &DamageOverTime,
+            &StatusOf,
+            Option<&Stacks>,
+            Option<&Instigator>,
+        ),
+        With<StatusApplied>,
+    >,
+    health_query: Query<(), With<Health>>,
+) {
+    for (dot, status_of, stacks, instigator) in &status_query {
+        let ticks = dot.tick.times_finished_this_tick();
+        if ticks == 0 || !health_query.contains(status_of.0) {
+            continue;
+        }
+
+        commands.trigger(AttemptDamage {
+            entity: status_of.0,
+            ignore_invulnerable: true,
+            damage: Damage::Single(
+                dot.damage * (ticks * stacks.map_or(1, |stacks| stacks.0)) as f32,
+            ),
+            damage_type: dot.damage_type,
+            instigator: instigator.map(|instigator| instigator.0),
+            ..default()
+        });
+    }
+}
+
+fn dot_vfx(
+    sprites: &SpriteAssets,
+    sprite_layouts: &SpriteSheetLayouts,
+    visual: DotVisual,
+    status: Entity,
+) -> impl Bundle {
+    (
+        StatusVfxOf(status),
+        Sprite::from_atlas_image(
+            sprites.flame.clone(),
+            TextureAtlas {
+                layout: sprite_layouts.flame_vfx.clone(),
+                ..default()
+            },
+        ),
+        Transform {
+            translation: Vec3::new(
+                0.0,
+                CHARACTER_FEET_POS_OFFSET + 8.0,
+                ZLayer::SpriteForeground.z(),
+            ),
+            scale: Vec3::new(visual.scale(), visual.scale(), 1.0),
+            ..default()
+        },
+        PointLight2d {
+            color: visual.light(),
+            intensity: 2.0,
+            falloff: 10.0,
+            outer_radius: 150.0,
+            ..default()
+        },
+        AnimationIndices::Cycle((0..=7).cycle()),
+        AnimationTimer(Timer::from_seconds(0.1, TimerMode::Repeating)),
+    )
+}
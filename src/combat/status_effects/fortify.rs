@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+use crate::combat::status_effects::StatusType;
+
+/// Buff that hardens whoever it is on, spawn it alongside `Armor` or `DamageReduction` to say by how much
+#[derive(Component, Clone, Default)]
+#[require(StatusType::Fortify)]
+pub struct Fortified;
@@ -2,7 +2,9 @@ use bevy::{color::palettes::tailwind::BLUE_400, prelude::*};
 use bevy_lit::prelude::PointLight2d;
 
 use crate::{
-    combat::status_effects::{StatusApplied, StatusOf, StatusType, StatusVfxOf, slow::Slowed},
+    combat::status_effects::{
+        CausedBy, StatusApplied, StatusOf, StatusType, StatusVfxOf, slow::Slowed,
+    },
     prelude::*,
 };
 
@@ -10,31 +12,45 @@ use crate::{
 #[require(StatusType::Freeze)]
 pub struct Frozen;
 
-pub(super) fn apply_frozen(
+/// Frozen targets can't move until they thaw, the stun goes away with the freeze that caused it
+pub(super) fn on_frozen(
+    status: On<Insert, StatusOf>,
     mut commands: Commands,
-    status_query: Query<(Entity, &StatusOf, &Lifespan), (With<Frozen>, Without<StatusApplied>)>,
-    sprites: Res<SpriteAssets>,
+    frozen_query: Query<(&StatusOf, &Lifespan), With<Frozen>>,
+    immunity_query: Query<&StatusImmunities>,
 ) {
-    status_query
-        .iter()
-        .for_each(|(status, status_of, duration)| {
-            commands.entity(status).insert(StatusApplied);
+    let Ok((status_of, duration)) = frozen_query.get(status.entity) else {
+        return;
+    };
+
+    if immunity_query
+        .get(status_of.0)
+        .is_ok_and(|immunities| immunities.contains(StatusType::Slow))
+    {
+        return;
+    }
 
-            let slowed = commands
-                .spawn((
-                    Slowed {
-                        percent: 1.0, // completely stun
-                    },
-                    // make sure slow lasts while frozen
-                    Lifespan::new(duration.0.remaining_secs()),
-                ))
-                .id();
+    commands.spawn((
+        Slowed {
+            percent: 1.0, // completely stun
+        },
+        StatusOf(status_of.0),
+        CausedBy(status.entity),
+        Lifespan::new(duration.0.remaining_secs()),
+    ));
+}
 
-            commands
-                .entity(status_of.0)
-                .add_one_related::<StatusOf>(slowed)
-                .with_child(grounded_ice_vfx(&sprites, status));
-        });
+pub(super) fn apply_frozen(
+    mut commands: Commands,
+    status_query: Query<(Entity, &StatusOf), (With<Frozen>, Without<StatusApplied>)>,
+    sprites: Res<SpriteAssets>,
+) {
+    status_query.iter().for_each(|(status, status_of)| {
+        commands.entity(status).insert(StatusApplied);
+        commands
+            .entity(status_of.0)
+            .with_child(grounded_ice_vfx(&sprites, status));
+    });
 }
 
 fn grounded_ice_vfx(sprites: &SpriteAssets, status: Entity) -> impl Bundle {
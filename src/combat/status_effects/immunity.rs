@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::combat::status_effects::{StatusType, Statuses};
+
+/// Crowd control applied again within this many seconds of the last time is diminished
+const DIMINISHING_RETURNS_WINDOW: f32 = 10.0;
+/// Each repeat within the window lasts this much as long as the one before it
+const DIMINISHING_RETURNS_FACTOR: f32 = 0.5;
+/// Repeats past this within the window are shrugged off entirely
+const MAX_DIMINISHED_APPLICATIONS: u32 = 3;
+
+/// Status types that never take hold on a character
+#[derive(Component, Clone, Debug, Default, Deserialize)]
+pub struct StatusImmunities(pub Vec<StatusType>);
+
+impl StatusImmunities {
+    pub fn contains(&self, status_type: StatusType) -> bool {
+        self.0.contains(&status_type)
+    }
+}
+
+/// Recent crowd control on a character, so it can't be chain-frozen or stunned forever
+#[derive(Component, Default, Debug)]
+pub struct DiminishingReturns {
+    /// Applications within the current window and when the last one happened, by status type
+    recent: HashMap<StatusType, (u32, f32)>,
+}
+
+impl DiminishingReturns {
+    /// Records an application at `now` (elapsed seconds), returning how much of its normal duration it lasts.
+    /// 0.0 means it was shrugged off
+    pub fn record(&mut self, status_type: StatusType, now: f32) -> f32 {
+        let (applications, last_applied) = self.recent.entry(status_type).or_insert((0, now));
+
+        if now - *last_applied > DIMINISHING_RETURNS_WINDOW {
+            *applications = 0;
+        }
+
+        if *applications >= MAX_DIMINISHED_APPLICATIONS {
+            return 0.0;
+        }
+
+        let multiplier = DIMINISHING_RETURNS_FACTOR.powi(*applications as i32);
+        *applications += 1;
+        *last_applied = now;
+        multiplier
+    }
+}
+
+/// Strips every negative status off an entity, along with their visual effects
+#[derive(EntityEvent)]
+pub struct Cleanse {
+    pub entity: Entity,
+}
+
+pub(super) fn on_cleanse(
+    cleanse: On<Cleanse>,
+    mut commands: Commands,
+    statuses_query: Query<&Statuses>,
+    status_query: Query<(Entity, &StatusType)>,
+) {
+    let Ok(statuses) = statuses_query.get(cleanse.entity) else {
+        return;
+    };
+
+    for (status, status_type) in status_query.iter_many(statuses.iter()) {
+        if status_type.is_negative() {
+            debug!("Cleansing {:?} status", status_type);
+            // Visual effects are linked to the status, so they go with it
+            commands.entity(status).despawn();
+        }
+    }
+}
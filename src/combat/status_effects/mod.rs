@@ -1,25 +1,54 @@
+mod bleed;
 mod burn;
+mod damage_over_time;
+mod fortify;
 mod freeze;
+mod immunity;
+mod poison;
+mod reactions;
 mod slow;
 
 pub mod prelude {
+    pub use super::bleed::Bleeding;
     pub use super::burn::*;
+    pub use super::damage_over_time::{DamageOverTime, DotVisual};
+    pub use super::fortify::*;
     pub use super::freeze::*;
-    pub use super::{EffectOf, Effects, StatusOf};
+    pub use super::immunity::*;
+    pub use super::poison::*;
+    pub use super::reactions::{ElementalReaction, Reactions};
+    pub use super::{
+        ApplyEffects, EffectOf, Effects, StackingPolicy, Stacks, StatusOf, StatusType,
+    };
 }
 
 use bevy::{ecs::entity_disabling::Disabled, platform::collections::HashSet, prelude::*};
+use serde::Deserialize;
 
-use crate::prelude::{InGameSystems, Lifespan};
+use crate::{
+    combat::damage::Instigator,
+    prelude::{InGameSystems, Lifespan},
+};
+use immunity::{DiminishingReturns, StatusImmunities};
+use reactions::{ElementalReaction, Reactions};
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_observer(apply_effects);
+    app.add_systems(Startup, reactions::setup_reactions)
+        .add_observer(apply_effects)
+        .add_observer(immunity::on_cleanse)
+        .add_observer(reactions::on_elemental_reaction)
+        .add_observer(freeze::on_frozen);
 
     app.add_systems(
         Update,
         (
-            burn::apply_burning,
-            (burn::tick_burn, burn::while_burning).chain(),
+            damage_over_time::apply_damage_over_time,
+            (
+                bleed::bleed_faster_while_moving,
+                damage_over_time::tick_damage_over_time,
+                damage_over_time::deal_damage_over_time,
+            )
+                .chain(),
             freeze::apply_frozen,
             slow::apply_slowed,
         )
@@ -49,13 +78,56 @@ pub struct Statuses(Vec<Entity>);
 #[derive(Component, Clone)]
 struct StatusApplied;
 
-#[derive(Component, Clone, Hash, Eq, PartialEq, Debug)]
-enum StatusType {
+#[derive(Component, Clone, Copy, Hash, Eq, PartialEq, Debug, Deserialize)]
+pub enum StatusType {
+    Bleed,
     Burn,
+    Fortify,
     Freeze,
+    Poison,
     Slow,
 }
 
+impl StatusType {
+    fn stacking_policy(self) -> StackingPolicy {
+        match self {
+            StatusType::Bleed => StackingPolicy::StackIntensity { max_stacks: 3 },
+            StatusType::Burn | StatusType::Fortify => StackingPolicy::RefreshDuration,
+            // Frozen targets can't be frozen again until they thaw, so they can't be perma-frozen
+            StatusType::Freeze => StackingPolicy::IgnoreWhileActive,
+            StatusType::Poison => StackingPolicy::StackIntensity { max_stacks: 5 },
+            StatusType::Slow => StackingPolicy::Replace,
+        }
+    }
+
+    /// Statuses a cleanse removes
+    pub fn is_negative(self) -> bool {
+        !matches!(self, StatusType::Fortify)
+    }
+
+    /// Statuses that take control away from their target, repeats of these have diminishing returns
+    pub fn is_crowd_control(self) -> bool {
+        matches!(self, StatusType::Freeze | StatusType::Slow)
+    }
+}
+
+/// What happens when a status is applied to a target that already has one of the same `StatusType`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StackingPolicy {
+    /// The existing status is removed and the new one applied in its place
+    Replace,
+    /// The existing status stays and its duration starts over
+    RefreshDuration,
+    /// The existing status gains a stack, up to `max_stacks`, and its duration starts over
+    StackIntensity { max_stacks: u32 },
+    /// Nothing happens until the existing status runs out
+    IgnoreWhileActive,
+}
+
+/// How many times a status has been stacked on its target, statuses without it count as a single stack
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stacks(pub u32);
+
 #[derive(Component, Clone)]
 #[relationship(relationship_target = StatusVisualEffects)]
 pub(super) struct StatusVfxOf(pub(super) Entity);
@@ -64,10 +136,21 @@ pub(super) struct StatusVfxOf(pub(super) Entity);
 #[relationship_target(relationship = StatusVfxOf, linked_spawn)]
 pub(super) struct StatusVisualEffects(Vec<Entity>);
 
+/// Statuses that only exist because of another one, like the stun from a freeze, and end along with it
+#[derive(Component, Clone)]
+#[relationship(relationship_target = CausedStatuses)]
+pub(super) struct CausedBy(pub(super) Entity);
+
+#[derive(Component, Clone)]
+#[relationship_target(relationship = CausedBy, linked_spawn)]
+pub(super) struct CausedStatuses(Vec<Entity>);
+
 #[derive(Event)]
 pub struct ApplyEffects {
     effects: Vec<Entity>,
     target: Entity,
+    /// Character applying the effects, credited with any damage the statuses deal
+    instigator: Option<Entity>,
 }
 
 impl ApplyEffects {
@@ -75,23 +158,35 @@ impl ApplyEffects {
         Self {
             effects: effects.0.clone(),
             target,
+            instigator: None,
         }
     }
+
+    pub fn with_instigator(mut self, instigator: Option<Entity>) -> Self {
+        self.instigator = instigator;
+        self
+    }
 }
 
 fn apply_effects(
     apply_effects: On<ApplyEffects>,
     mut commands: Commands,
-    effect_query: Query<&StatusType, (Without<StatusOf>, Allow<Disabled>)>,
-    affected_query: Query<&Statuses>,
-    status_query: Query<&StatusType, With<StatusOf>>,
+    time: Res<Time>,
+    reactions: Res<Reactions>,
+    effect_query: Query<(&StatusType, Option<&Lifespan>), (Without<StatusOf>, Allow<Disabled>)>,
+    mut affected_query: Query<(
+        Option<&Statuses>,
+        Option<&StatusImmunities>,
+        Option<&mut DiminishingReturns>,
+    )>,
+    status_query: Query<(Entity, &StatusType, Option<&Stacks>), With<StatusOf>>,
 ) {
     let mut new_status_types = HashSet::new();
     let deduped_effects: Vec<Entity> = apply_effects
         .effects
         .iter()
         .filter_map(|&entity| {
-            if let Ok(status_type) = effect_query.get(entity) {
+            if let Ok((status_type, _)) = effect_query.get(entity) {
                 if new_status_types.insert(status_type) {
                     Some(entity)
                 } else {
@@ -105,24 +200,546 @@ fn apply_effects(
         })
         .collect();
 
-    // Remove any existing statuses on the target that conflict with new effects
-    if let Ok(statuses) = affected_query.get(apply_effects.target) {
-        for existing_entity in statuses.iter() {
-            if let Ok(existing_type) = status_query.get(existing_entity)
-                && new_status_types.contains(existing_type)
-            {
-                debug!("Removing existing status");
-                commands.entity(existing_entity).despawn();
+    let Ok((statuses, immunities, mut diminishing_returns)) =
+        affected_query.get_mut(apply_effects.target)
+    else {
+        return;
+    };
+
+    let existing_statuses: Vec<_> = statuses
+        .map(|statuses| status_query.iter_many(statuses.iter()).collect())
+        .unwrap_or_default();
+    // Targets start tracking diminishing returns the first time they are crowd controlled
+    let mut new_diminishing_returns = None;
+    // Statuses used up by reactions while applying these effects
+    let mut consumed_statuses = HashSet::new();
+
+    debug!("Applying effects: {:?}", deduped_effects);
+    for effect in deduped_effects {
+        let Ok((&status_type, lifespan)) = effect_query.get(effect) else {
+            continue;
+        };
+
+        if immunities.is_some_and(|immunities| immunities.contains(status_type)) {
+            debug!("Target is immune to {:?}", status_type);
+            continue;
+        }
+
+        if set_off_reaction(
+            &mut commands,
+            &reactions,
+            apply_effects.target,
+            apply_effects.instigator,
+            status_type,
+            &existing_statuses,
+            &mut consumed_statuses,
+        ) {
+            continue;
+        }
+
+        let existing = existing_statuses
+            .iter()
+            .filter(|(entity, _, _)| !consumed_statuses.contains(entity))
+            .find(|(_, existing_type, _)| **existing_type == status_type);
+
+        if let Some(&(existing_entity, _, stacks)) = existing {
+            match status_type.stacking_policy() {
+                // Handled below, once we know the new status isn't shrugged off
+                StackingPolicy::Replace => {}
+                StackingPolicy::RefreshDuration => {
+                    commands
+                        .entity(existing_entity)
+                        .insert(lifespan.cloned().unwrap_or_default());
+                    continue;
+                }
+                StackingPolicy::StackIntensity { max_stacks } => {
+                    let stacks = stacks.map_or(1, |stacks| stacks.0);
+                    commands.entity(existing_entity).insert((
+                        Stacks((stacks + 1).min(max_stacks)),
+                        lifespan.cloned().unwrap_or_default(),
+                    ));
+                    continue;
+                }
+                StackingPolicy::IgnoreWhileActive => continue,
             }
         }
+
+        let mut lifespan = lifespan.cloned().unwrap_or_default();
+        if status_type.is_crowd_control() {
+            let diminishing_returns = match diminishing_returns.as_deref_mut() {
+                Some(diminishing_returns) => diminishing_returns,
+                None => new_diminishing_returns.get_or_insert_with(DiminishingReturns::default),
+            };
+
+            let multiplier = diminishing_returns.record(status_type, time.elapsed_secs());
+            if multiplier <= 0.0 {
+                debug!("Target shrugged off repeated {:?}", status_type);
+                continue;
+            }
+            lifespan = Lifespan::new(lifespan.0.duration().as_secs_f32() * multiplier);
+        }
+
+        if let Some(&(existing_entity, _, _)) = existing {
+            debug!("Replacing existing {:?} status", status_type);
+            commands.entity(existing_entity).despawn();
+        }
+
+        spawn_status(&mut commands, effect, &apply_effects, lifespan);
     }
 
-    debug!("Applying effects: {:?}", deduped_effects);
-    for effect in deduped_effects {
+    if let Some(diminishing_returns) = new_diminishing_returns {
         commands
-            .entity(effect)
-            .clone_and_spawn()
-            .remove::<(Disabled, EffectOf)>()
-            .insert(StatusOf(apply_effects.target));
+            .entity(apply_effects.target)
+            .try_insert(diminishing_returns);
+    }
+}
+
+/// Spawns a copy of the `effect` template on the target as an active status
+fn spawn_status(
+    commands: &mut Commands,
+    effect: Entity,
+    apply_effects: &ApplyEffects,
+    lifespan: Lifespan,
+) {
+    let status = commands
+        .entity(effect)
+        .clone_and_spawn()
+        .remove::<(Disabled, EffectOf)>()
+        .insert((StatusOf(apply_effects.target), lifespan))
+        .id();
+
+    // Whoever first applied the status is credited with the damage it deals
+    if let Some(instigator) = apply_effects.instigator {
+        commands.entity(status).insert(Instigator(instigator));
+    }
+}
+
+/// Sets off the reaction between `status_type` and a status already on `target`, like fire on ice.
+/// Returns whether the reaction used up the status being applied
+fn set_off_reaction(
+    commands: &mut Commands,
+    reactions: &Reactions,
+    target: Entity,
+    instigator: Option<Entity>,
+    status_type: StatusType,
+    existing_statuses: &[(Entity, &StatusType, Option<&Stacks>)],
+    consumed_statuses: &mut HashSet<Entity>,
+) -> bool {
+    let Some((index, reaction, existing_entity)) = existing_statuses
+        .iter()
+        .filter(|(entity, _, _)| !consumed_statuses.contains(entity))
+        .find_map(|&(entity, &existing_type, _)| {
+            let index = reactions.find(status_type, existing_type)?;
+            Some((index, reactions.get(index)?, entity))
+        })
+    else {
+        return false;
+    };
+
+    commands.trigger(ElementalReaction {
+        entity: target,
+        reaction: index,
+        instigator,
+    });
+    if reaction.consumes_existing {
+        commands.entity(existing_entity).despawn();
+        consumed_statuses.insert(existing_entity);
+    }
+    reaction.consumes_applied
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        prelude::*,
+        reactions::{Reaction, ReactionVfx},
+        slow::Slowed,
+        *,
+    };
+    use crate::prelude::SimpleMotion;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Reactions>()
+            .add_observer(apply_effects)
+            .add_observer(immunity::on_cleanse);
+        app
+    }
+
+    fn advance_time(app: &mut App, seconds: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(seconds));
+    }
+
+    /// Spawns an effect template the way weapons carry them, returning the template entity.
+    /// `EffectOf` requires `Disabled`, so the template itself never runs as a status
+    fn spawn_effect(app: &mut App, effect: impl Bundle) -> Entity {
+        let source = app.world_mut().spawn_empty().id();
+        app.world_mut().spawn((effect, EffectOf(source))).id()
+    }
+
+    fn apply(app: &mut App, effect: Entity, target: Entity) {
+        app.world_mut().trigger(ApplyEffects {
+            effects: vec![effect],
+            target,
+            instigator: None,
+        });
+        app.world_mut().flush();
+    }
+
+    fn statuses(app: &App, target: Entity) -> Vec<Entity> {
+        app.world()
+            .get::<Statuses>(target)
+            .map(|statuses| statuses.iter().collect())
+            .unwrap_or_default()
+    }
+
+    fn age_status(app: &mut App, status: Entity, seconds: f32) {
+        app.world_mut()
+            .get_mut::<Lifespan>(status)
+            .unwrap()
+            .0
+            .tick(Duration::from_secs_f32(seconds));
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    fn remaining_secs(app: &App, status: Entity) -> f32 {
+        app.world()
+            .get::<Lifespan>(status)
+            .unwrap()
+            .0
+            .remaining_secs()
+    }
+
+    #[test]
+    fn first_application_spawns_status() {
+        let mut app = test_app();
+        let target = app.world_mut().spawn_empty().id();
+        let burn = spawn_effect(&mut app, (Burning, Lifespan::new(2.5)));
+
+        apply(&mut app, burn, target);
+
+        let statuses = statuses(&app, target);
+        assert_eq!(statuses.len(), 1);
+        assert!(app.world().get::<Burning>(statuses[0]).is_some());
+        assert!(app.world().get::<Disabled>(statuses[0]).is_none());
+        assert!(app.world().get::<EffectOf>(statuses[0]).is_none());
+    }
+
+    #[test]
+    fn replace_swaps_in_new_status() {
+        let mut app = test_app();
+        let target = app.world_mut().spawn_empty().id();
+        let weak_slow = spawn_effect(&mut app, (Slowed { percent: 0.2 }, Lifespan::new(2.0)));
+        let strong_slow = spawn_effect(&mut app, (Slowed { percent: 0.8 }, Lifespan::new(2.0)));
+
+        apply(&mut app, weak_slow, target);
+        let first = statuses(&app, target)[0];
+        apply(&mut app, strong_slow, target);
+
+        let statuses = statuses(&app, target);
+        assert_eq!(statuses.len(), 1);
+        assert_ne!(statuses[0], first);
+        assert!(app.world().get_entity(first).is_err());
+        assert_close(app.world().get::<Slowed>(statuses[0]).unwrap().percent, 0.8);
+    }
+
+    #[test]
+    fn refresh_restarts_duration_of_existing_status() {
+        let mut app = test_app();
+        let target = app.world_mut().spawn_empty().id();
+        let burn = spawn_effect(&mut app, (Burning, Lifespan::new(2.5)));
+
+        apply(&mut app, burn, target);
+        let first = statuses(&app, target)[0];
+        age_status(&mut app, first, 2.0);
+        apply(&mut app, burn, target);
+
+        assert_eq!(statuses(&app, target), vec![first]);
+        assert_close(remaining_secs(&app, first), 2.5);
+    }
+
+    #[test]
+    fn stack_intensity_adds_stacks_up_to_max() {
+        let mut app = test_app();
+        let target = app.world_mut().spawn_empty().id();
+        let poison = spawn_effect(&mut app, (Poisoned, Lifespan::new(3.0)));
+        let StackingPolicy::StackIntensity { max_stacks } = StatusType::Poison.stacking_policy()
+        else {
+            panic!("Poison should stack intensity");
+        };
+
+        apply(&mut app, poison, target);
+        let first = statuses(&app, target)[0];
+        assert_eq!(app.world().get::<Stacks>(first), None);
+
+        apply(&mut app, poison, target);
+        assert_eq!(app.world().get::<Stacks>(first), Some(&Stacks(2)));
+
+        age_status(&mut app, first, 2.0);
+        for _ in 0..max_stacks + 2 {
+            apply(&mut app, poison, target);
+        }
+
+        assert_eq!(statuses(&app, target), vec![first]);
+        assert_eq!(app.world().get::<Stacks>(first), Some(&Stacks(max_stacks)));
+        assert_close(remaining_secs(&app, first), 3.0);
+    }
+
+    #[test]
+    fn ignore_while_active_keeps_existing_status_untouched() {
+        let mut app = test_app();
+        let target = app.world_mut().spawn_empty().id();
+        let short_freeze = spawn_effect(&mut app, (Frozen, Lifespan::new(2.0)));
+        let long_freeze = spawn_effect(&mut app, (Frozen, Lifespan::new(5.0)));
+
+        apply(&mut app, short_freeze, target);
+        let first = statuses(&app, target)[0];
+        age_status(&mut app, first, 1.5);
+        apply(&mut app, long_freeze, target);
+
+        assert_eq!(statuses(&app, target), vec![first]);
+        assert_close(remaining_secs(&app, first), 0.5);
+    }
+
+    #[test]
+    fn different_status_types_coexist() {
+        let mut app = test_app();
+        let target = app.world_mut().spawn_empty().id();
+        let burn = spawn_effect(&mut app, (Burning, Lifespan::new(2.5)));
+        let poison = spawn_effect(&mut app, (Poisoned, Lifespan::new(3.0)));
+
+        apply(&mut app, burn, target);
+        apply(&mut app, poison, target);
+
+        assert_eq!(statuses(&app, target).len(), 2);
+    }
+
+    #[test]
+    fn immune_targets_ignore_status() {
+        let mut app = test_app();
+        let target = app
+            .world_mut()
+            .spawn(StatusImmunities(vec![StatusType::Freeze]))
+            .id();
+        let freeze = spawn_effect(&mut app, (Frozen, Lifespan::new(2.0)));
+        let burn = spawn_effect(&mut app, (Burning, Lifespan::new(2.5)));
+
+        apply(&mut app, freeze, target);
+        apply(&mut app, burn, target);
+
+        let statuses = statuses(&app, target);
+        assert_eq!(statuses.len(), 1);
+        assert!(app.world().get::<Burning>(statuses[0]).is_some());
+    }
+
+    #[test]
+    fn repeated_crowd_control_is_diminished_then_resisted() {
+        let mut app = test_app();
+        let target = app.world_mut().spawn_empty().id();
+        let freeze = spawn_effect(&mut app, (Frozen, Lifespan::new(2.0)));
+
+        for expected in [2.0, 1.0, 0.5] {
+            apply(&mut app, freeze, target);
+            let statuses = statuses(&app, target);
+            assert_eq!(statuses.len(), 1);
+            assert_close(remaining_secs(&app, statuses[0]), expected);
+
+            // Thaw out before the next freeze lands
+            app.world_mut().despawn(statuses[0]);
+            advance_time(&mut app, 1.0);
+        }
+
+        apply(&mut app, freeze, target);
+        assert!(statuses(&app, target).is_empty());
+
+        // Once the window passes the target can be frozen for the full duration again
+        advance_time(&mut app, 11.0);
+        apply(&mut app, freeze, target);
+        let statuses = statuses(&app, target);
+        assert_eq!(statuses.len(), 1);
+        assert_close(remaining_secs(&app, statuses[0]), 2.0);
+    }
+
+    #[test]
+    fn cleanse_removes_negative_statuses_and_their_vfx() {
+        let mut app = test_app();
+        let target = app.world_mut().spawn_empty().id();
+        let burn = spawn_effect(&mut app, (Burning, Lifespan::new(2.5)));
+        let fortify = spawn_effect(&mut app, (Fortified, Lifespan::new(5.0)));
+
+        apply(&mut app, burn, target);
+        apply(&mut app, fortify, target);
+        let burn_status = statuses(&app, target)
+            .into_iter()
+            .find(|status| app.world().get::<Burning>(*status).is_some())
+            .unwrap();
+        let vfx = app.world_mut().spawn(StatusVfxOf(burn_status)).id();
+
+        app.world_mut().trigger(Cleanse { entity: target });
+        app.world_mut().flush();
+
+        let statuses = statuses(&app, target);
+        assert_eq!(statuses.len(), 1);
+        assert!(app.world().get::<Fortified>(statuses[0]).is_some());
+        assert!(app.world().get_entity(burn_status).is_err());
+        assert!(app.world().get_entity(vfx).is_err());
+    }
+
+    #[test]
+    fn freeze_stun_ends_with_the_freeze() {
+        let mut app = test_app();
+        app.add_observer(freeze::on_frozen);
+        let target = app.world_mut().spawn_empty().id();
+        let freeze = spawn_effect(&mut app, (Frozen, Lifespan::new(2.0)));
+
+        apply(&mut app, freeze, target);
+        let (frozen, stun): (Vec<_>, Vec<_>) = statuses(&app, target)
+            .into_iter()
+            .partition(|status| app.world().get::<Frozen>(*status).is_some());
+        assert_eq!((frozen.len(), stun.len()), (1, 1));
+        assert_close(app.world().get::<Slowed>(stun[0]).unwrap().percent, 1.0);
+
+        app.world_mut().despawn(frozen[0]);
+
+        assert!(statuses(&app, target).is_empty());
+    }
+
+    #[test]
+    fn slow_immune_targets_are_frozen_without_being_stunned() {
+        let mut app = test_app();
+        app.add_observer(freeze::on_frozen);
+        let target = app
+            .world_mut()
+            .spawn(StatusImmunities(vec![StatusType::Slow]))
+            .id();
+        let freeze = spawn_effect(&mut app, (Frozen, Lifespan::new(2.0)));
+
+        apply(&mut app, freeze, target);
+
+        let statuses = statuses(&app, target);
+        assert_eq!(statuses.len(), 1);
+        assert!(app.world().get::<Frozen>(statuses[0]).is_some());
+    }
+
+    fn reaction(applied: StatusType, existing: StatusType, consumes_applied: bool) -> Reaction {
+        Reaction {
+            name: format!("{applied:?} on {existing:?}"),
+            applied,
+            existing,
+            consumes_applied,
+            consumes_existing: true,
+            damage: None,
+            vfx: ReactionVfx {
+                color: (1.0, 1.0, 1.0),
+                scale: 1.0,
+            },
+        }
+    }
+
+    #[test]
+    fn reaction_consumes_both_statuses() {
+        let mut app = test_app();
+        app.insert_resource(Reactions(vec![reaction(
+            StatusType::Burn,
+            StatusType::Freeze,
+            true,
+        )]));
+        let target = app.world_mut().spawn_empty().id();
+        let freeze = spawn_effect(&mut app, (Frozen, Lifespan::new(2.0)));
+        let burn = spawn_effect(&mut app, (Burning, Lifespan::new(2.5)));
+
+        apply(&mut app, freeze, target);
+        apply(&mut app, burn, target);
+
+        assert!(statuses(&app, target).is_empty());
+    }
+
+    #[test]
+    fn target_can_move_after_its_freeze_is_shattered() {
+        let mut app = test_app();
+        app.insert_resource(Reactions(vec![reaction(
+            StatusType::Burn,
+            StatusType::Freeze,
+            true,
+        )]))
+        .add_systems(Update, slow::apply_slowed)
+        .add_observer(slow::on_slow_removed)
+        .add_observer(freeze::on_frozen);
+        let target = app.world_mut().spawn(SimpleMotion::new(100.0)).id();
+        let freeze = spawn_effect(&mut app, (Frozen, Lifespan::new(2.0)));
+        let burn = spawn_effect(&mut app, (Burning, Lifespan::new(2.5)));
+
+        apply(&mut app, freeze, target);
+        app.update();
+        assert!(
+            app.world()
+                .get::<SimpleMotion>(target)
+                .unwrap()
+                .is_stunned()
+        );
+
+        apply(&mut app, burn, target);
+
+        assert!(statuses(&app, target).is_empty());
+        assert!(
+            !app.world()
+                .get::<SimpleMotion>(target)
+                .unwrap()
+                .is_stunned()
+        );
+    }
+
+    #[test]
+    fn reaction_can_let_applied_status_take_hold() {
+        let mut app = test_app();
+        app.insert_resource(Reactions(vec![reaction(
+            StatusType::Freeze,
+            StatusType::Burn,
+            false,
+        )]));
+        let target = app.world_mut().spawn_empty().id();
+        let burn = spawn_effect(&mut app, (Burning, Lifespan::new(2.5)));
+        let freeze = spawn_effect(&mut app, (Frozen, Lifespan::new(2.0)));
+
+        apply(&mut app, burn, target);
+        apply(&mut app, freeze, target);
+
+        let statuses = statuses(&app, target);
+        assert_eq!(statuses.len(), 1);
+        assert!(app.world().get::<Frozen>(statuses[0]).is_some());
+    }
+
+    #[test]
+    fn status_remembers_who_applied_it() {
+        let mut app = test_app();
+        let attacker = app.world_mut().spawn_empty().id();
+        let target = app.world_mut().spawn_empty().id();
+        let poison = spawn_effect(&mut app, (Poisoned, Lifespan::new(3.0)));
+        let effects = app.world().get::<EffectOf>(poison).unwrap().0;
+        let effects = app.world().get::<Effects>(effects).unwrap().clone();
+
+        app.world_mut()
+            .trigger(ApplyEffects::new(&effects, target).with_instigator(Some(attacker)));
+        app.world_mut().flush();
+
+        let statuses = statuses(&app, target);
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(
+            app.world()
+                .get::<Instigator>(statuses[0])
+                .map(|instigator| instigator.0),
+            Some(attacker)
+        );
     }
 }
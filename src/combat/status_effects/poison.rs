@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+use crate::combat::{
+    damage::DamageType,
+    health::HealingReduction,
+    status_effects::{StatusType, damage_over_time::DamageOverTime},
+};
+
+/// Poison seeps past armor and halves any healing while it lasts
+#[derive(Component, Clone, Default)]
+#[require(
+    StatusType::Poison,
+    DamageOverTime::new(1.0, DamageType::True, 1.0),
+    HealingReduction(0.5)
+)]
+pub struct Poisoned;
@@ -0,0 +1,201 @@
+use std::io::BufReader;
+
+use bevy::{prelude::*, scene::ron::de::from_reader};
+use bevy_lit::prelude::PointLight2d;
+use serde::Deserialize;
+
+use crate::{
+    combat::{
+        damage::{AttemptDamage, Damage, DamageType},
+        status_effects::StatusType,
+    },
+    prelude::*,
+};
+
+/// What happens when a status is applied to a target already suffering from another, like fire on ice
+#[derive(Deserialize, Debug)]
+pub struct Reaction {
+    pub name: String,
+    /// Status being applied
+    pub applied: StatusType,
+    /// Status already on the target
+    pub existing: StatusType,
+    /// The applied status never takes hold
+    pub consumes_applied: bool,
+    /// The existing status is removed
+    pub consumes_existing: bool,
+    pub damage: Option<ReactionDamage>,
+    pub vfx: ReactionVfx,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ReactionDamage {
+    pub amount: f32,
+    pub damage_type: DamageType,
+    /// 0.0 only hurts the target, anything bigger also hurts the target's allies within range
+    pub radius: f32,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ReactionVfx {
+    pub color: (f32, f32, f32),
+    pub scale: f32,
+}
+
+#[derive(Deserialize, Debug)]
+struct ReactionConfig {
+    reactions: Vec<Reaction>,
+}
+
+/// Every elemental reaction, loaded from `config/reactions.ron`
+#[derive(Resource, Default)]
+pub struct Reactions(pub Vec<Reaction>);
+
+impl Reactions {
+    /// Index of the reaction between a status being applied and one already on the target, if there is one
+    pub fn find(&self, applied: StatusType, existing: StatusType) -> Option<usize> {
+        self.0
+            .iter()
+            .position(|reaction| reaction.applied == applied && reaction.existing == existing)
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Reaction> {
+        self.0.get(index)
+    }
+}
+
+/// A reaction went off on `entity`, index into `Reactions`
+#[derive(EntityEvent)]
+pub struct ElementalReaction {
+    pub entity: Entity,
+    pub reaction: usize,
+    /// Character who applied the status that set off the reaction
+    pub instigator: Option<Entity>,
+}
+
+pub(super) fn on_elemental_reaction(
+    elemental_reaction: On<ElementalReaction>,
+    mut commands: Commands,
+    reactions: Res<Reactions>,
+    sprites: Res<SpriteAssets>,
+    sprite_layouts: Res<SpriteSheetLayouts>,
+    target_query: Query<(&Transform, Option<&Faction>)>,
+    nearby_query: Query<(Entity, &Transform, Option<&Faction>), With<Health>>,
+) {
+    let Some(reaction) = reactions.get(elemental_reaction.reaction) else {
+        return;
+    };
+    let Ok((target_transform, target_faction)) = target_query.get(elemental_reaction.entity) else {
+        return;
+    };
+    let position = target_transform.translation.xy();
+
+    debug!(
+        "{} reaction on {}",
+        reaction.name, elemental_reaction.entity
+    );
+
+    if let Some(damage) = &reaction.damage {
+        let mut hit = vec![elemental_reaction.entity];
+        if damage.radius > 0.0 {
+            hit.extend(
+                nearby_query
+                    .iter()
+                    .filter(|(entity, transform, faction)| {
+                        *entity != elemental_reaction.entity
+                            && *faction == target_faction
+                            && transform.translation.xy().distance(position) <= damage.radius
+                    })
+                    .map(|(entity, _, _)| entity),
+            );
+        }
+
+        for entity in hit {
+            let direction = nearby_query.get(entity).ok().and_then(|(_, transform, _)| {
+                (transform.translation.xy() - position).try_normalize()
+            });
+
+            commands.trigger(AttemptDamage {
+                entity,
+                ignore_invulnerable: true,
+                damage: Damage::Single(damage.amount),
+                damage_type: damage.damage_type,
+                instigator: elemental_reaction.instigator,
+                direction,
+                ..default()
+            });
+        }
+    }
+
+    commands.spawn(reaction_vfx(
+        &sprites,
+        &sprite_layouts,
+        &reaction.vfx,
+        position,
+    ));
+}
+
+fn reaction_vfx(
+    sprites: &SpriteAssets,
+    sprite_layouts: &SpriteSheetLayouts,
+    vfx: &ReactionVfx,
+    position: Vec2,
+) -> impl Bundle {
+    let color = Color::srgb(vfx.color.0, vfx.color.1, vfx.color.2);
+
+    (
+        Name::new("Reaction VFX"),
+        Sprite {
+            image: sprites.tome_of_healing_effect.clone(),
+            texture_atlas: Some(TextureAtlas {
+                layout: sprite_layouts.spell_effect.clone(),
+                index: 0,
+            }),
+            color,
+            ..default()
+        },
+        Transform {
+            translation: position.extend(ZLayer::InAir.z()),
+            scale: Vec3::new(vfx.scale, vfx.scale, 1.0),
+            ..default()
+        },
+        PointLight2d {
+            color,
+            intensity: 2.0,
+            falloff: 5.0,
+            outer_radius: 60.0 * vfx.scale,
+            ..default()
+        },
+        AnimationIndices::OneShot(0..=9),
+        AnimationTimer(Timer::from_seconds(0.05, TimerMode::Repeating)),
+        Lifespan::new(0.5),
+    )
+}
+
+pub(super) fn setup_reactions(mut commands: Commands) {
+    commands.insert_resource(Reactions(load_reaction_data()));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs::File;
+
+#[cfg(not(target_arch = "wasm32"))]
+fn fetch_reaction_data() -> File {
+    File::open("assets/config/reactions.ron").expect("Failed to open RON file")
+}
+
+#[cfg(target_arch = "wasm32")]
+fn fetch_reaction_data() -> &'static [u8] {
+    include_bytes!("../../../assets/config/reactions.ron")
+}
+
+fn load_reaction_data() -> Vec<Reaction> {
+    let reader = BufReader::new(fetch_reaction_data());
+
+    from_reader::<_, ReactionConfig>(reader)
+        .unwrap_or_else(|e| {
+            error!("Failed to parse RON file: {:?}", e);
+            panic!("RON parsing error");
+        })
+        .reactions
+}
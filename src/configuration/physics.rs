@@ -25,6 +25,7 @@ pub enum GameCollisionLayer {
     // For physical collisions
     LowObstacle, // Obstacle that stops ground movement but lets things "fly" over, like projectiles
     HighObstacle, // Obstacle that stops all movement
+    Reflector,   // Shields and auras that bounce projectiles, invisible to everything else
     Grounded,    // Marks entities that get stopped by all obstacles
     InAir,       // Marks entity as able to go over low obstacle (projectile, )
     EnemyCollider,
@@ -37,6 +38,8 @@ pub enum GameCollisionLayer {
 
 impl GameCollisionLayer {
     pub const PROJECTILE_MEMBERSHIPS: [GameCollisionLayer; 2] = [Self::HitBox, Self::InAir];
+    /// Non-hurtbox layers that stop or bounce projectiles
+    pub const PROJECTILE_FILTERS: [GameCollisionLayer; 2] = [Self::HighObstacle, Self::Reflector];
     pub const LOW_OBSTACLE_FILTERS: [GameCollisionLayer; 1] = [Self::Grounded];
     pub const HIGH_OBSTACLE_FILTERS: [GameCollisionLayer; 2] = [Self::Grounded, Self::InAir];
 }
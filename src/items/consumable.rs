@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::prelude::{AttemptHeal, SpriteAssets};
+use crate::prelude::{AttemptHeal, Cleanse, SpriteAssets};
 
 use super::{Item, ItemType};
 
@@ -11,6 +11,7 @@ pub struct Consumable {
 
 pub enum ConsumableType {
     Heal(f32), // Heal player for a specific amount
+    Cleanse,   // Remove negative statuses like burning and poison
 }
 
 #[derive(EntityEvent)]
@@ -30,6 +31,22 @@ pub fn health_potion(sprites: &SpriteAssets) -> impl Bundle {
     )
 }
 
+/// Warm tea that washes away burns, poison and any other lingering ailments
+pub fn antidote(sprites: &SpriteAssets) -> impl Bundle {
+    (
+        Name::new("Antidote"),
+        Item::new(60, ItemType::Potion),
+        Consumable {
+            effect: ConsumableType::Cleanse,
+        },
+        Sprite {
+            // No art of its own yet, a green tinted health potion
+            color: Color::srgb(0.5, 1.0, 0.5),
+            ..Sprite::from_image(sprites.health_potion.clone())
+        },
+    )
+}
+
 pub(super) fn on_consume_event(
     consume: On<Consume>,
     mut commands: Commands,
@@ -45,6 +62,11 @@ pub(super) fn on_consume_event(
                     amount: *amount,
                 });
             }
+            ConsumableType::Cleanse => {
+                commands.trigger(Cleanse {
+                    entity: consume.entity,
+                });
+            }
         }
         commands.entity(item_entity).despawn();
     }
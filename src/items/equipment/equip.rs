@@ -5,7 +5,7 @@ use crate::{
     character::Character,
     items::{
         ItemOf,
-        equipment::{Equipped, Mainhand, MainhandOf, Offhand, OffhandOf},
+        equipment::{Equipped, HiddenWhenEquipped, Mainhand, MainhandOf, Offhand, OffhandOf},
         melee::ActiveMeleeAttack,
     },
     prelude::AttackState,
@@ -27,10 +27,15 @@ pub struct Equip {
 fn on_equip(
     equipped: On<Equip>,
     mut commands: Commands,
-    mut item_query: Query<(&Equippable, &mut Visibility, Has<ItemOf>)>,
+    mut item_query: Query<(
+        &Equippable,
+        &mut Visibility,
+        Has<ItemOf>,
+        Has<HiddenWhenEquipped>,
+    )>,
     mut holder_query: Query<(Option<&Mainhand>, Option<&Offhand>), With<Character>>,
 ) {
-    let (equippable, mut visibility, in_inventory) = item_query
+    let (equippable, mut visibility, in_inventory, hidden_when_equipped) = item_query
         .get_mut(equipped.item)
         .expect("Added Equipped to non-equippable item");
 
@@ -71,7 +76,9 @@ fn on_equip(
     }
 
     // Make sure item is now visible, since it is hidden while in inventory
-    *visibility = Visibility::Visible;
+    if !hidden_when_equipped {
+        *visibility = Visibility::Visible;
+    }
 }
 
 #[derive(EntityEvent)]
@@ -14,7 +14,8 @@ pub mod prelude {
     pub use super::equipment_transform::*;
     pub use super::use_equipped::*;
     pub use super::{
-        EquipmentSlot, Equippable, Equipped, Mainhand, MainhandOf, Offhand, OffhandOf,
+        EquipmentSlot, Equippable, Equipped, HiddenWhenEquipped, Mainhand, MainhandOf, Offhand,
+        OffhandOf,
     };
 }
 
@@ -76,6 +77,10 @@ impl fmt::Display for EquipmentSlot {
 #[derive(Component, Clone, Debug)]
 pub struct Equipped;
 
+/// Equipment without a sprite of its own, like a creature's fangs, that stays hidden once equipped
+#[derive(Component, Clone, Debug)]
+pub struct HiddenWhenEquipped;
+
 #[derive(Component, Clone)]
 #[relationship(relationship_target = Mainhand)]
 pub struct MainhandOf(pub Entity);
@@ -1,7 +1,7 @@
 mod swing;
 
 pub mod prelude {
-    pub use super::{ActiveMeleeAttack, axe, freeze_axe, sword};
+    pub use super::{ActiveMeleeAttack, axe, bat_fangs, freeze_axe, sword};
 }
 
 use std::sync::LazyLock;
@@ -31,7 +31,8 @@ pub(super) fn plugin(app: &mut App) {
     app.add_systems(
         Update,
         handle_melee_collisions.in_set(InGameSystems::Collision),
-    );
+    )
+    .add_observer(on_holder_faction_changed);
 }
 
 pub fn sword(sprites: &SpriteAssets) -> impl Bundle {
@@ -39,12 +40,15 @@ pub fn sword(sprites: &SpriteAssets) -> impl Bundle {
         Name::new("Sword"),
         MeleeWeapon {
             damage: (1.0, 6.0),
+            damage_type: DamageType::Physical,
             hitbox: Collider::rectangle(10.0, 40.0),
             attack_type: MeleeSwingType::STAB,
             attack_time: 0.2,
             hold_distance: 15.0,
         },
         Knockback(10.0),
+        // Quick stabs find the gaps in armor
+        CritChance(0.1),
         Equippable::new(EquipmentSlot::Mainhand, 0.4, &MELEE_EQUIPMENT_TRANSFORMS),
         Item::new(120, ItemType::Melee),
         Sprite::from_image(sprites.sword.clone()),
@@ -58,12 +62,16 @@ pub fn axe(sprites: &SpriteAssets) -> impl Bundle {
         Name::new("Axe"),
         MeleeWeapon {
             damage: (2.0, 12.0),
+            damage_type: DamageType::Physical,
             hitbox: Collider::rectangle(10.0, 40.0),
             attack_type: MeleeSwingType::SLASH,
             attack_time: 0.3,
             hold_distance: 30.0,
         },
         Knockback(20.0),
+        CritChance(0.05),
+        // Deep cuts keep bleeding, worse for anyone trying to run
+        related!(Effects[(Bleeding, Lifespan::new(4.0))]),
         Equippable::new(EquipmentSlot::Mainhand, 0.4, &MELEE_EQUIPMENT_TRANSFORMS),
         Sprite::from_image(sprites.axe.clone()),
         Item::new(220, ItemType::Melee),
@@ -77,6 +85,7 @@ pub fn freeze_axe(sprites: &SpriteAssets) -> impl Bundle {
         Name::new("Freeze Axe"),
         MeleeWeapon {
             damage: (2.0, 12.0),
+            damage_type: DamageType::Ice,
             hitbox: Collider::rectangle(10.0, 40.0),
             attack_type: MeleeSwingType::SLASH,
             attack_time: 0.3,
@@ -92,6 +101,32 @@ pub fn freeze_axe(sprites: &SpriteAssets) -> impl Bundle {
     )
 }
 
+/// Natural weapon for bats, it has no sprite and can't be dropped
+pub fn bat_fangs() -> impl Bundle {
+    (
+        Name::new("Bat Fangs"),
+        MeleeWeapon {
+            damage: (1.0, 3.0),
+            damage_type: DamageType::Physical,
+            hitbox: Collider::circle(12.0),
+            attack_type: MeleeSwingType::STAB,
+            attack_time: 0.15,
+            hold_distance: 8.0,
+        },
+        Knockback(2.0),
+        related!(Effects[(Poisoned, Lifespan::new(3.0))]),
+        Equippable::new(EquipmentSlot::Mainhand, 0.3, &MELEE_EQUIPMENT_TRANSFORMS),
+        Item {
+            drop_rate: 0.0,
+            ..Item::new(0, ItemType::Melee)
+        },
+        Visibility::Hidden,
+        HiddenWhenEquipped,
+        observe(on_melee_equipped),
+        observe(on_weapon_melee),
+    )
+}
+
 //Repesent a melee weapon
 #[derive(Component, Clone, Debug)]
 #[require(ContextPriority::<MeleeWeapon>::new(1))]
@@ -99,16 +134,16 @@ struct MeleeWeapon {
     // Time it takes (seconds) to complete the attack, smaller = faster
     attack_time: f32,
     damage: (f32, f32),
+    damage_type: DamageType,
     hitbox: Collider,
     attack_type: swing::MeleeSwingType,
     hold_distance: f32,
 }
 
 impl MeleeWeapon {
-    /// Gets collision layers for melee weapon based on source of damage
-    /// It can either target allies or enemies
-    pub fn collision_layers(damage_source: DamageSource) -> CollisionLayers {
-        CollisionLayers::new(GameCollisionLayer::HitBox, LayerMask::from(damage_source))
+    /// Gets collision layers for melee weapon based on the faction of its holder
+    pub fn collision_layers(faction: Faction) -> CollisionLayers {
+        CollisionLayers::new(GameCollisionLayer::HitBox, faction.hostile_layers())
     }
 }
 
@@ -125,28 +160,38 @@ fn on_melee_equipped(
     equipped: On<Equip>,
     mut commands: Commands,
     weapon_query: Query<&MeleeWeapon, With<Equippable>>,
-    player: Single<Entity, With<Player>>,
+    holder_query: Query<&Faction>,
 ) {
     let Ok(melee_weapon) = weapon_query.get(equipped.item) else {
         error!("on_melee_equipped on wrong type");
         return;
     };
 
-    let is_player = *player == equipped.holder;
-
-    let damage_source = if is_player {
-        DamageSource::Player
-    } else {
-        DamageSource::Enemy
+    let Ok(faction) = holder_query.get(equipped.holder) else {
+        warn!("Melee weapon equipped by holder without a faction");
+        return;
     };
 
     // If melee weapon, we need to add collider and new collision layers on equip
     commands.entity(equipped.item).insert((
         melee_weapon.hitbox.clone(),
-        MeleeWeapon::collision_layers(damage_source),
+        MeleeWeapon::collision_layers(*faction),
     ));
 }
 
+/// If the holder switches sides, so does their weapon
+fn on_holder_faction_changed(
+    faction_changed: On<Insert, Faction>,
+    holder_query: Query<(&Faction, &Mainhand)>,
+    mut weapon_query: Query<&mut CollisionLayers, (With<MeleeWeapon>, With<Equipped>)>,
+) {
+    if let Ok((faction, mainhand)) = holder_query.get(faction_changed.entity)
+        && let Ok(mut layers) = weapon_query.get_mut(mainhand.get())
+    {
+        *layers = MeleeWeapon::collision_layers(*faction);
+    }
+}
+
 fn handle_melee_collisions(
     mut commands: Commands,
     mut melee_query: Query<(
@@ -154,11 +199,26 @@ fn handle_melee_collisions(
         &MeleeWeapon,
         &mut ActiveMeleeAttack,
         &CollidingEntities,
+        Option<&ItemOf>,
+        Option<&CritChance>,
     )>,
+    holder_query: Query<&DerivedStats>,
 ) {
-    for (weapon_entity, melee_weapon, mut active_melee_attack, colliding_entities) in
-        &mut melee_query
+    for (
+        weapon_entity,
+        melee_weapon,
+        mut active_melee_attack,
+        colliding_entities,
+        item_of,
+        crit_chance,
+    ) in &mut melee_query
     {
+        // Holders without stats (enemies) swing at the weapon's base damage
+        let power = item_of
+            .and_then(|item_of| holder_query.get(item_of.0).ok())
+            .map_or_else(AttackPower::default, DerivedStats::melee_power)
+            .with_weapon(crit_chance);
+
         for &colliding_entity in colliding_entities.iter() {
             // We only hit a given entity once per attack
             if !active_melee_attack
@@ -168,9 +228,13 @@ fn handle_melee_collisions(
                 commands.trigger(AttemptDamage {
                     entity: colliding_entity,
                     ignore_invulnerable: false,
-                    damage: Damage::Range(melee_weapon.damage),
+                    damage: Damage::Range(melee_weapon.damage).scaled(power.damage_multiplier),
+                    damage_type: melee_weapon.damage_type,
                     damage_source: Some(weapon_entity),
+                    instigator: item_of.map(|item_of| item_of.0),
                     direction: Some(Vec2::from_angle(active_melee_attack.initial_angle)),
+                    crit_chance: power.crit_chance,
+                    crit_multiplier: power.crit_multiplier,
                 });
                 active_melee_attack
                     .entities_damaged
@@ -204,14 +268,14 @@ fn on_weapon_melee(
     melee: On<UseEquipment>,
     mut commands: Commands,
     weapon_query: Query<(&ItemOf, &MeleeWeapon)>,
-    mut holder_query: Query<(&mut AttackState, &Vision)>,
+    mut holder_query: Query<(&mut AttackState, &Vision, &Transform)>,
 ) {
     let Ok((item_of, melee_weapon)) = weapon_query.get(melee.entity) else {
         warn!("Tried to melee attack with invalid weapon");
         return;
     };
 
-    let Ok((mut attack_state, vision)) = holder_query.get_mut(item_of.0) else {
+    let Ok((mut attack_state, vision, holder_transform)) = holder_query.get_mut(item_of.0) else {
         warn!("Holder missing vision");
         return;
     };
@@ -219,6 +283,12 @@ fn on_weapon_melee(
     let attack_angle = vision.aim_direction.to_angle();
     attack_state.is_attacking = true;
 
+    commands.trigger(Noise::new(
+        holder_transform.translation.xy(),
+        Noise::MELEE_SWING,
+        item_of.0,
+    ));
+
     commands.entity(melee.entity).insert(ActiveMeleeAttack::new(
         attack_angle,
         melee_weapon.attack_time,
@@ -39,6 +39,7 @@ pub fn magic_shield(
         ProjectileReflection,
         Shield {
             hitbox: Collider::rectangle(25.0, 25.0),
+            block: 0.0,
         },
         Sprite {
             image: sprites.magic_shield.clone(),
@@ -67,7 +68,9 @@ pub fn knight_shield(
         ),
         Shield {
             hitbox: Collider::rectangle(25.0, 25.0),
+            block: 0.5,
         },
+        Armor(10.0),
         ManaDrainRate(25.0),
         ManaCost(25.0),
         Sprite {
@@ -86,6 +89,8 @@ pub fn knight_shield(
 #[derive(Component)]
 pub struct Shield {
     pub hitbox: Collider,
+    /// Fraction of incoming damage soaked up while the shield is raised
+    pub block: f32,
 }
 
 #[derive(Component, Default)]
@@ -94,15 +99,26 @@ pub struct ProjectileReflection;
 
 impl ProjectileReflection {
     fn collision_layers() -> CollisionLayers {
-        CollisionLayers::new(GameCollisionLayer::HighObstacle, GameCollisionLayer::InAir)
+        CollisionLayers::new(GameCollisionLayer::Reflector, GameCollisionLayer::InAir)
     }
 }
 
-#[derive(Component)]
+#[derive(Component, Default)]
 struct ActiveShield {
     projectiles_reflected: HashSet<Entity>,
 }
 
+/// An always-active circular shield, spawned as a child of whoever it protects
+pub fn reflection_aura(radius: f32) -> impl Bundle {
+    (
+        Name::new("Reflection Aura"),
+        ProjectileReflection,
+        ActiveShield::default(),
+        Collider::circle(radius),
+        ProjectileReflection::collision_layers(),
+    )
+}
+
 fn update_active_shields(
     mut commands: Commands,
     time: Res<Time>,
@@ -175,13 +191,17 @@ fn update_active_shields(
 fn on_shield_block(
     used_shield: On<UseEquipment>,
     mut commands: Commands,
-    mut shield_query: Query<&Shield>,
+    mut shield_query: Query<(&Shield, &ItemOf)>,
 ) {
-    let Ok(shield) = shield_query.get_mut(used_shield.entity) else {
+    let Ok((shield, item_of)) = shield_query.get_mut(used_shield.entity) else {
         warn!("Tried to block with invalid shield");
         return;
     };
 
+    if shield.block > 0.0 {
+        commands.entity(item_of.0).insert(Blocking(shield.block));
+    }
+
     commands.entity(used_shield.entity).insert((
         ActiveShield {
             projectiles_reflected: HashSet::default(),
@@ -192,6 +212,7 @@ fn on_shield_block(
 }
 
 fn reflect_projectiles(
+    mut commands: Commands,
     mut shield_query: Query<
         (&mut ActiveShield, &CollidingEntities, &ChildOf),
         With<ProjectileReflection>,
@@ -200,7 +221,7 @@ fn reflect_projectiles(
         (&mut LinearVelocity, &mut CollisionLayers, &mut Transform),
         With<Projectile>,
     >,
-    enemy_query: Query<&Enemy>,
+    holder_query: Query<&Faction>,
 ) {
     for (mut shield, colliding_entities, child_of) in &mut shield_query {
         for &colliding_entity in colliding_entities.iter() {
@@ -210,8 +231,18 @@ fn reflect_projectiles(
             if let Ok((mut linear_velocity, mut collision_layers, mut transform)) =
                 projectile_query.get_mut(colliding_entity)
             {
-                // If shield blocker is enemy, reflected projectile can now hurt the player! (and vice-versa)
-                let is_blocker_enemy = enemy_query.contains(child_of.parent());
+                // Reflected projectile now belongs to the blocker's faction, so it can hurt whoever fired it
+                let Ok(blocker_faction) = holder_query.get(child_of.parent()) else {
+                    continue;
+                };
+
+                // Projectiles that can't hurt the blocker are friendly and pass straight through
+                if !collision_layers
+                    .filters
+                    .has_all(blocker_faction.hurtbox_layer())
+                {
+                    continue;
+                }
 
                 // Reverse direction of projectile! Reflect!
                 linear_velocity.0 = -linear_velocity.0;
@@ -221,9 +252,13 @@ fn reflect_projectiles(
 
                 *collision_layers = CollisionLayers::new(
                     GameCollisionLayer::PROJECTILE_MEMBERSHIPS,
-                    LayerMask::from(DamageSource::from(is_blocker_enemy))
-                        | GameCollisionLayer::HighObstacle,
+                    blocker_faction.hostile_layers()
+                        | LayerMask::from(GameCollisionLayer::PROJECTILE_FILTERS),
                 );
+                // Whoever reflected the projectile is credited with the damage it deals on the way back
+                commands
+                    .entity(colliding_entity)
+                    .insert(Instigator(child_of.parent()));
                 shield.projectiles_reflected.insert(colliding_entity);
             }
         }
@@ -249,6 +284,7 @@ fn on_shield_deactivated(
         return;
     };
 
+    commands.entity(item_of.0).remove::<Blocking>();
     commands
         .entity(shield.entity)
         .remove::<(ActiveShield, Collider)>()
@@ -4,9 +4,13 @@ use std::{
     time::Duration,
 };
 
-use bevy::{platform::collections::HashMap, prelude::*, ui_widgets::observe};
+use bevy::{
+    ecs::entity_disabling::Disabled, platform::collections::HashMap, prelude::*,
+    ui_widgets::observe,
+};
 use bevy_lit::prelude::PointLight2d;
 use bevy_tweening::{Tween, TweenAnim, lens::TransformRotateZLens};
+use rand::{Rng, rng};
 
 use crate::{
     equipment_transforms,
@@ -58,6 +62,7 @@ pub fn ice_staff(sprites: &SpriteAssets, sprite_layouts: &SpriteSheetLayouts) ->
             return_time: 0.15,
         },
         Item::new(2050, ItemType::Staff),
+        CritChance(0.1),
         ManaCost(15.0), // big mana cost
         Equippable::new(EquipmentSlot::Mainhand, 1.0, &STAFF_EQUIPMENT_TRANSFORMS),
         Sprite::from_image(sprites.ice_staff.clone()),
@@ -165,6 +170,21 @@ fn on_staff_fired(
         ));
 }
 
+/// Moves the aim toward where a moving target will be when the projectile arrives, then scatters it
+/// based on how sloppy the shooter is
+fn lead_target(offset: Vec2, target_velocity: Vec2, speed: f32, accuracy: Accuracy) -> Vec2 {
+    let lead = intercept_time(offset, target_velocity, speed)
+        .map_or(Vec2::ZERO, |time| target_velocity * time);
+    let aim = offset + lead * accuracy.lead();
+
+    let spread = accuracy.spread();
+    if spread > 0.0 {
+        Vec2::from_angle(rng().random_range(-spread..spread)).rotate(aim)
+    } else {
+        aim
+    }
+}
+
 fn tick_casting_timer(casting_query: Query<&mut Casting>, time: Res<Time>) {
     for mut casting in casting_query {
         casting.return_time.tick(time.delta());
@@ -181,22 +201,35 @@ fn fire_projectile_on_casting_end(
         &Projectiles,
         &ItemOf,
         &Transform,
+        Option<&CritChance>,
     )>,
     mut holder_query: Query<(
         &mut AttackState,
         &Transform,
         &Vision,
         Option<&TargetInfo>,
-        Has<Enemy>,
+        Option<&Accuracy>,
+        Option<&DerivedStats>,
+        &Faction,
     )>,
+    projectile_query: Query<&Projectile, Allow<Disabled>>,
 ) {
-    for (staff_entity, staff, mut casting, projectiles, item_of, staff_transform) in staff_query {
+    for (staff_entity, staff, mut casting, projectiles, item_of, staff_transform, crit_chance) in
+        staff_query
+    {
         if !casting.fire_time.is_finished() {
             continue;
         }
 
-        let Ok((mut attack_state, holder_transform, holder_vision, target_info, is_enemy)) =
-            holder_query.get_mut(item_of.0)
+        let Ok((
+            mut attack_state,
+            holder_transform,
+            holder_vision,
+            target_info,
+            accuracy,
+            derived_stats,
+            faction,
+        )) = holder_query.get_mut(item_of.0)
         else {
             warn!("Tried to fire staff with holder missing aim position or transform");
             continue;
@@ -212,21 +245,43 @@ fn fire_projectile_on_casting_end(
                 staff_transform.translation.truncate() + rotated_source_offset;
 
             // If no target ditance default to sane value away from holder
-            let target_angle: Vec2 = (holder_vision.aim_direction
+            let mut target_angle: Vec2 = (holder_vision.aim_direction
                 * target_info.map_or(100., |t| t.distance))
                 - holder_relative_source_pos;
 
+            // AI leads moving targets as well as its accuracy allows
+            if let (Some(accuracy), Some(target_info)) = (accuracy, target_info) {
+                let speed = projectiles
+                    .iter()
+                    .find_map(|p| projectile_query.get(p).ok())
+                    .map_or(0.0, |p| p.speed);
+                target_angle = lead_target(target_angle, target_info.velocity, speed, *accuracy);
+            }
+
             // Staff is child of holder so position is relative, need to add holder transform for global position
             let world_starting_position =
                 holder_transform.translation.truncate() + holder_relative_source_pos;
 
+            commands.trigger(Noise::new(
+                world_starting_position,
+                Noise::SPELL_CAST,
+                item_of.0,
+            ));
+
+            let power = derived_stats
+                .map_or_else(AttackPower::default, DerivedStats::spell_power)
+                .with_weapon(crit_chance);
             for projectile_entity in projectiles.iter() {
-                commands.trigger(FireProjectile::from((
-                    projectile_entity,
-                    DamageSource::from(is_enemy),
-                    world_starting_position,
-                    target_angle,
-                )));
+                commands.trigger(
+                    FireProjectile::from((
+                        projectile_entity,
+                        *faction,
+                        world_starting_position,
+                        target_angle,
+                    ))
+                    .with_power(power)
+                    .with_instigator(item_of.0),
+                );
             }
         }
 
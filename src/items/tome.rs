@@ -13,8 +13,14 @@ use crate::{
 #[derive(Component)]
 struct HealingTome {
     pub healing: (f32, f32),
+    /// Heals the holder's `SpellTarget` instead of the holder
+    pub targeted: bool,
 }
 
+/// Who the holder's targeted spells land on (ex. the ally a support is healing)
+#[derive(Component)]
+pub struct SpellTarget(pub Entity);
+
 pub fn tome_of_healing(sprites: &SpriteAssets) -> impl Bundle {
     (
         Name::new("Tome Of Healing"),
@@ -27,28 +33,67 @@ pub fn tome_of_healing(sprites: &SpriteAssets) -> impl Bundle {
         ManaCost(40.0),
         HealingTome {
             healing: (25.0, 50.0),
+            targeted: false,
         },
         Sprite::from_image(sprites.tome_of_healing.clone()),
         observe(on_healing_tome_cast),
     )
 }
 
+/// Healing tome cast on someone else, used by support casters to keep their allies alive
+pub fn tome_of_mending(sprites: &SpriteAssets) -> impl Bundle {
+    (
+        Name::new("Tome Of Mending"),
+        Item::new(420, ItemType::Tome),
+        Equippable::new(
+            EquipmentSlot::Offhand,
+            3.0,
+            &DEFAULT_EQUIPMENT_TRANSFORM_MAP,
+        ),
+        ManaCost(30.0),
+        HealingTome {
+            healing: (10.0, 20.0),
+            targeted: true,
+        },
+        // Mended allies are toughened up for a little while
+        related!(Effects[(Fortified, Armor(25.0), Lifespan::new(6.0))]),
+        Sprite::from_image(sprites.tome_of_healing.clone()),
+        observe(on_healing_tome_cast),
+    )
+}
+
 fn on_healing_tome_cast(
     healing_tome: On<UseEquipment>,
     mut commands: Commands,
-    tome_query: Query<(&HealingTome, &ItemOf)>,
+    tome_query: Query<(&HealingTome, &ItemOf, Option<&Effects>)>,
+    holder_query: Query<Option<&SpellTarget>>,
+    health_query: Query<(), With<Health>>,
     sprites: Res<SpriteAssets>,
     sprite_layouts: Res<SpriteSheetLayouts>,
 ) -> Result {
-    let (tome, item_of) = tome_query.get(healing_tome.entity)?;
+    let (tome, item_of, effects) = tome_query.get(healing_tome.entity)?;
+
+    // Fall back to healing the holder if the target is gone
+    let target = holder_query
+        .get(item_of.0)?
+        .filter(|_| tome.targeted)
+        .map(|spell_target| spell_target.0)
+        .filter(|target| health_query.contains(*target))
+        .unwrap_or(item_of.0);
 
     let health_to_add = rand::rng().random_range(tome.healing.0..tome.healing.1);
     commands.trigger(AttemptHeal {
-        entity: item_of.0,
+        entity: target,
         amount: health_to_add,
     });
+
+    // Buffs ride along with the heal
+    if let Some(effects) = effects {
+        commands.trigger(ApplyEffects::new(effects, target).with_instigator(Some(item_of.0)));
+    }
+
     commands
-        .entity(item_of.0)
+        .entity(target)
         .with_child(heal_tome_vfx(sprites, sprite_layouts));
 
     Ok(())
@@ -6,13 +6,28 @@ use crate::prelude::{DamageDealt, Healed, Lifespan, ZLayer};
 
 const RED_COLOR: Color = Color::srgb(1.0, 0.0, 0.0);
 const GREEN_COLOR: Color = Color::srgb(0.0, 0.8, 0.0);
+const CRIT_COLOR: Color = Color::srgb(1.0, 0.65, 0.0);
 const HEALTH_TEXT_OFFSET: f32 = 10.0;
+const TEXT_LIFESPAN: f32 = 0.4;
+const CRIT_FONT_SIZE: f32 = 30.0;
+/// Crits hang around a little longer so the shake can be seen
+const CRIT_LIFESPAN: f32 = 0.7;
+/// Max distance in pixels a critical number jitters away from where it spawned
+const CRIT_SHAKE_DISTANCE: f32 = 2.0;
+
+/// Jitters text around the position it was spawned at
+#[derive(Component)]
+pub(super) struct Shake {
+    origin: Vec3,
+    distance: f32,
+}
 
 fn spawn_health_change_text(
     commands: &mut Commands,
     entity: Entity,
     amount: f32,
     color: Color,
+    is_critical: bool,
     collider_query: &Query<&ColliderAabb>,
 ) {
     let entity_height = if let Ok(collider) = collider_query.get(entity) {
@@ -40,12 +55,29 @@ fn spawn_health_change_text(
         format!("{rounded_amount:.1}") // Display with one decimal place
     };
 
-    commands.entity(entity).with_child((
-        Text2d::new(formatted_amount),
-        TextColor::from(color),
-        Lifespan::new(0.4),
-        Transform::from_translation(text_position),
-    ));
+    if is_critical {
+        commands.entity(entity).with_child((
+            Text2d::new(format!("{formatted_amount}!")),
+            TextFont {
+                font_size: CRIT_FONT_SIZE,
+                ..default()
+            },
+            TextColor::from(CRIT_COLOR),
+            Lifespan::new(CRIT_LIFESPAN),
+            Transform::from_translation(text_position),
+            Shake {
+                origin: text_position,
+                distance: CRIT_SHAKE_DISTANCE,
+            },
+        ));
+    } else {
+        commands.entity(entity).with_child((
+            Text2d::new(formatted_amount),
+            TextColor::from(color),
+            Lifespan::new(TEXT_LIFESPAN),
+            Transform::from_translation(text_position),
+        ));
+    }
 }
 
 // Generate a random angle between -angle_range and angle_range degrees (convert to radians)
@@ -64,6 +96,7 @@ pub fn on_damage_overlay_amount(
         damage_dealt.entity,
         damage_dealt.damage,
         RED_COLOR,
+        damage_dealt.is_critical,
         &damaged_query,
     );
 }
@@ -78,6 +111,18 @@ pub fn on_healing_overlay_amount(
         healed.entity,
         healed.amount,
         GREEN_COLOR,
+        false,
         &healed_query,
     );
 }
+
+pub fn shake_text(mut shake_query: Query<(&Shake, &mut Transform)>) {
+    let mut rng = rand::rng();
+    for (shake, mut transform) in &mut shake_query {
+        let offset = Vec2::new(
+            rng.random_range(-shake.distance..shake.distance),
+            rng.random_range(-shake.distance..shake.distance),
+        );
+        transform.translation = shake.origin + offset.extend(0.0);
+    }
+}
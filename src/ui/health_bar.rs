@@ -0,0 +1,253 @@
+use bevy::prelude::*;
+
+use crate::{character::Character, prelude::*};
+
+const BAR_WIDTH: f32 = 32.0;
+const BAR_HEIGHT: f32 = 4.0;
+/// Height above the character's center, just under the name plate
+const BAR_OFFSET: f32 = 30.0;
+const NAME_PLATE_OFFSET: f32 = BAR_OFFSET + 8.0;
+/// How long a bar stays up after the character was hurt
+const SHOW_DURATION: f32 = 3.0;
+/// The last part of `SHOW_DURATION` is spent fading out
+const FADE_DURATION: f32 = 1.0;
+
+const BAR_BACKGROUND_COLOR: Color = Color::srgb(0.21, 0.21, 0.21);
+const HEALTH_COLOR: Color = Color::srgb(1.0, 0.0, 0.0);
+const BOSS_NAME_COLOR: Color = Color::srgb(0.9, 0.75, 0.4);
+
+const BOSS_BAR_WIDTH: f32 = 600.0;
+const BOSS_BAR_HEIGHT: f32 = 20.0;
+
+/// World-space health bar floating above a character, hidden until they get hurt or targeted
+#[derive(Component)]
+pub(super) struct OverheadHealthBar {
+    shown: Timer,
+}
+
+#[derive(Component)]
+pub(super) struct OverheadHealthFill;
+
+/// Name floating above an elite or boss. Inserting it again swaps the text, a character never has two
+#[derive(Component)]
+pub struct NamePlate {
+    pub name: String,
+    pub color: Color,
+}
+
+#[derive(Component)]
+pub(super) struct NamePlateText;
+
+/// Screen-space health bar for a `Boss`
+#[derive(Component)]
+pub(super) struct BossHealthBar {
+    boss: Entity,
+}
+
+#[derive(Component)]
+pub(super) struct BossHealthFill;
+
+pub(super) fn on_character_health_added(
+    health_added: On<Add, Health>,
+    mut commands: Commands,
+    character_query: Query<(), (With<Character>, Without<Player>)>,
+) {
+    if !character_query.contains(health_added.entity) {
+        return;
+    }
+
+    let mut shown = Timer::from_seconds(SHOW_DURATION, TimerMode::Once);
+    shown.finish();
+
+    commands.entity(health_added.entity).with_child((
+        Name::new("Overhead Health Bar"),
+        OverheadHealthBar { shown },
+        Sprite::from_color(BAR_BACKGROUND_COLOR, Vec2::new(BAR_WIDTH, BAR_HEIGHT)),
+        Transform::from_xyz(0.0, BAR_OFFSET, ZLayer::AboveSprite.z()),
+        Visibility::Hidden,
+        children![(
+            OverheadHealthFill,
+            Sprite::from_color(HEALTH_COLOR, Vec2::new(BAR_WIDTH, BAR_HEIGHT)),
+            // Just in front of the background
+            Transform::from_xyz(0.0, 0.0, 0.01),
+        )],
+    ));
+}
+
+pub(super) fn on_damage_show_health_bar(
+    damage_dealt: On<DamageDealt>,
+    children_query: Query<&Children>,
+    mut bar_query: Query<&mut OverheadHealthBar>,
+) {
+    let Ok(children) = children_query.get(damage_dealt.entity) else {
+        return;
+    };
+
+    let mut bars = bar_query.iter_many_mut(children);
+    while let Some(mut bar) = bars.fetch_next() {
+        bar.shown.reset();
+    }
+}
+
+pub(super) fn update_overhead_health_bars(
+    time: Res<Time>,
+    mut bar_query: Query<(
+        &mut OverheadHealthBar,
+        &mut Sprite,
+        &mut Visibility,
+        &ChildOf,
+        &Children,
+    )>,
+    mut fill_query: Query<
+        (&mut Sprite, &mut Transform),
+        (With<OverheadHealthFill>, Without<OverheadHealthBar>),
+    >,
+    owner_query: Query<(&Health, Has<TargetedBy>)>,
+) {
+    for (mut bar, mut sprite, mut visibility, child_of, children) in &mut bar_query {
+        let Ok((health, is_targeted)) = owner_query.get(child_of.parent()) else {
+            continue;
+        };
+
+        if is_targeted {
+            bar.shown.reset();
+        }
+        bar.shown.tick(time.delta());
+
+        if bar.shown.is_finished() || health.is_dead() {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+
+        let alpha = (bar.shown.remaining_secs() / FADE_DURATION).min(1.0);
+        sprite.color.set_alpha(alpha);
+
+        let fraction = health.fraction().clamp(0.0, 1.0);
+        for child in children {
+            if let Ok((mut fill_sprite, mut fill_transform)) = fill_query.get_mut(*child) {
+                fill_sprite.color.set_alpha(alpha);
+                fill_sprite.custom_size = Some(Vec2::new(BAR_WIDTH * fraction, BAR_HEIGHT));
+                // Keep the bar anchored to the left as it shrinks
+                fill_transform.translation.x = -BAR_WIDTH * (1.0 - fraction) / 2.0;
+            }
+        }
+    }
+}
+
+pub(super) fn on_name_plate_inserted(
+    name_plate: On<Insert, NamePlate>,
+    mut commands: Commands,
+    plate_query: Query<(&NamePlate, Option<&Children>)>,
+    text_query: Query<(), With<NamePlateText>>,
+) {
+    let Ok((plate, children)) = plate_query.get(name_plate.entity) else {
+        return;
+    };
+
+    for child in children.into_iter().flatten() {
+        if text_query.contains(*child) {
+            commands.entity(*child).despawn();
+        }
+    }
+
+    commands.entity(name_plate.entity).with_child((
+        Name::new("Name Plate"),
+        NamePlateText,
+        Text2d::new(plate.name.clone()),
+        TextFont {
+            font_size: 10.0,
+            ..default()
+        },
+        TextColor::from(plate.color),
+        Transform::from_xyz(0.0, NAME_PLATE_OFFSET, ZLayer::AboveSprite.z()),
+    ));
+}
+
+pub(super) fn on_boss_spawned(
+    boss_spawned: On<Add, Boss>,
+    mut commands: Commands,
+    boss_query: Query<&Name>,
+) {
+    let name = boss_query
+        .get(boss_spawned.entity)
+        .map_or_else(|_| "Boss".to_string(), ToString::to_string);
+
+    // Elite bosses keep their elite name plate
+    commands
+        .entity(boss_spawned.entity)
+        .insert_if_new(NamePlate {
+            name: name.clone(),
+            color: BOSS_NAME_COLOR,
+        });
+
+    commands.spawn((
+        Name::new("Boss Health Bar"),
+        BossHealthBar {
+            boss: boss_spawned.entity,
+        },
+        DespawnOnExit(AppState::Playing),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(20.0),
+            width: percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: px(6.0),
+            ..default()
+        },
+        children![
+            (
+                Text::new(name),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor::from(BOSS_NAME_COLOR),
+            ),
+            (
+                Node {
+                    width: px(BOSS_BAR_WIDTH),
+                    height: px(BOSS_BAR_HEIGHT),
+                    ..default()
+                },
+                BackgroundColor::from(BAR_BACKGROUND_COLOR),
+                children![(
+                    BossHealthFill,
+                    Node {
+                        width: px(BOSS_BAR_WIDTH),
+                        height: px(BOSS_BAR_HEIGHT),
+                        ..default()
+                    },
+                    BackgroundColor::from(HEALTH_COLOR),
+                )],
+            ),
+        ],
+    ));
+}
+
+pub(super) fn update_boss_health_bars(
+    mut commands: Commands,
+    bar_query: Query<(Entity, &BossHealthBar)>,
+    children_query: Query<&Children>,
+    mut fill_query: Query<&mut Node, With<BossHealthFill>>,
+    boss_query: Query<&Health, With<Boss>>,
+) {
+    for (bar_entity, boss_bar) in &bar_query {
+        let Ok(health) = boss_query.get(boss_bar.boss).map(Health::fraction) else {
+            commands.entity(bar_entity).despawn();
+            continue;
+        };
+
+        if health <= 0.0 {
+            commands.entity(bar_entity).despawn();
+            continue;
+        }
+
+        for fill in children_query.iter_descendants(bar_entity) {
+            if let Ok(mut node) = fill_query.get_mut(fill) {
+                node.width = px(BOSS_BAR_WIDTH * health.clamp(0.0, 1.0));
+            }
+        }
+    }
+}
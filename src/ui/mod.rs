@@ -1,7 +1,10 @@
 pub mod constants;
 mod damage_overlay;
 mod game_over_screen;
+mod health_bar;
 mod load_screen;
 pub mod plugin;
 pub mod primitives;
 mod start_screen;
+
+pub use health_bar::NamePlate;
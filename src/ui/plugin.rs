@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 use crate::{
     prelude::*,
-    ui::{damage_overlay, game_over_screen, load_screen, start_screen},
+    ui::{damage_overlay, game_over_screen, health_bar, load_screen, start_screen},
 };
 
 /// Plugin responsible for managing all UI-related systems and state transitions
@@ -27,7 +27,25 @@ impl Plugin for UIPlugin {
 
         // Heal and damage overlays
         app.add_observer(damage_overlay::on_damage_overlay_amount)
-            .add_observer(damage_overlay::on_healing_overlay_amount);
+            .add_observer(damage_overlay::on_healing_overlay_amount)
+            .add_systems(
+                Update,
+                damage_overlay::shake_text.in_set(InGameSystems::HudOverlay),
+            );
+
+        // Health bars and name plates over enemies and NPCs, plus the boss bar
+        app.add_observer(health_bar::on_character_health_added)
+            .add_observer(health_bar::on_damage_show_health_bar)
+            .add_observer(health_bar::on_name_plate_inserted)
+            .add_observer(health_bar::on_boss_spawned)
+            .add_systems(
+                Update,
+                (
+                    health_bar::update_overhead_health_bars,
+                    health_bar::update_boss_health_bars,
+                )
+                    .in_set(InGameSystems::HudOverlay),
+            );
 
         // Game over systems
         app.add_systems(OnEnter(AppState::GameOver), game_over_screen::spawn);
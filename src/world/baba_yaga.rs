@@ -0,0 +1,247 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<BabaYagaClock>()
+        .init_resource::<HuntPath>()
+        .add_systems(OnEnter(AppState::SpawnZone), reset_baba_yaga_clock)
+        .add_systems(
+            Update,
+            (tick_baba_yaga_clock, update_hunt_path).in_set(InGameSystems::Simulation),
+        );
+}
+
+/// Seconds the player can spend in the first zone before Baba Yaga catches their scent
+const BASE_ARRIVAL_TIME: f32 = 120.0;
+/// She finds you quicker the deeper you go
+const ARRIVAL_TIME_PER_DEPTH: f32 = 10.0;
+const MIN_ARRIVAL_TIME: f32 = 45.0;
+/// How often the path toward the player is rebuilt
+const HUNT_PATH_REFRESH_SECS: f32 = 0.5;
+
+const ANNOUNCEMENT_COLOR: Color = Color::srgb(0.8, 0.1, 0.1);
+
+/// Counts down how long the player has been lingering in a zone. When it runs out Baba Yaga arrives
+#[derive(Resource)]
+pub struct BabaYagaClock {
+    timer: Timer,
+    arrived: bool,
+}
+
+impl Default for BabaYagaClock {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(BASE_ARRIVAL_TIME, TimerMode::Once),
+            arrived: false,
+        }
+    }
+}
+
+impl BabaYagaClock {
+    pub fn remaining_secs(&self) -> f32 {
+        self.timer.remaining_secs()
+    }
+
+    pub fn has_arrived(&self) -> bool {
+        self.arrived
+    }
+}
+
+/// Flow field over the zone's tiles pointing toward the player, so hunters can path around walls
+#[derive(Resource)]
+pub struct HuntPath {
+    /// Next tile to step onto from each tile, `None` for unreachable or unwalkable tiles
+    next_step: Vec<Vec<Option<IVec2>>>,
+    refresh: Timer,
+}
+
+impl Default for HuntPath {
+    fn default() -> Self {
+        Self {
+            next_step: Vec::new(),
+            refresh: Timer::from_seconds(HUNT_PATH_REFRESH_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+impl HuntPath {
+    /// Direction to walk from `position` to get closer to the player, `None` if there is no known path
+    pub fn direction_from(
+        &self,
+        position: Vec2,
+        world_config: &WorldSpaceConfig,
+        map_layout: &MapLayout,
+    ) -> Option<Vec2> {
+        let tile = world_config.world_to_tile(map_layout.size, position);
+        let next = *self
+            .next_step
+            .get(usize::try_from(tile.x).ok()?)?
+            .get(usize::try_from(tile.y).ok()?)?;
+
+        let next_position = world_config.tile_to_world(map_layout.size, next?);
+        (next_position - position).try_normalize()
+    }
+}
+
+fn reset_baba_yaga_clock(
+    mut clock: ResMut<BabaYagaClock>,
+    mut hunt_path: ResMut<HuntPath>,
+    zone_difficulty: Res<ZoneDifficulty>,
+) {
+    let arrival_time = (BASE_ARRIVAL_TIME - ARRIVAL_TIME_PER_DEPTH * zone_difficulty.depth as f32)
+        .max(MIN_ARRIVAL_TIME);
+
+    clock.timer = Timer::from_seconds(arrival_time, TimerMode::Once);
+    clock.arrived = false;
+    hunt_path.next_step.clear();
+}
+
+fn tick_baba_yaga_clock(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut clock: ResMut<BabaYagaClock>,
+    zone_difficulty: Res<ZoneDifficulty>,
+    map_layout: Res<MapLayout>,
+    world_config: Res<WorldSpaceConfig>,
+    player_transform: Single<&Transform, With<Player>>,
+) {
+    // She doesn't come to the hub
+    if zone_difficulty.depth == 0 || clock.arrived {
+        return;
+    }
+
+    if !clock.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let player_tile =
+        world_config.world_to_tile(map_layout.size, player_transform.translation.xy());
+    let Some(arrival_tile) = arrival_tile(&map_layout, player_tile) else {
+        warn!("No walkable tile for Baba Yaga to arrive on");
+        return;
+    };
+
+    clock.arrived = true;
+    info!("Baba Yaga has arrived");
+
+    commands.trigger(SpawnEnemies(vec![EnemySpawnData {
+        position: world_config.tile_to_world(map_layout.size, arrival_tile),
+        enemy_type: "BabaYaga".into(),
+        elite: None,
+        spawned_by: None,
+    }]));
+
+    commands.spawn((
+        Name::new("Baba Yaga Announcement"),
+        Lifespan::new(5.0),
+        DespawnOnExit(AppState::Playing),
+        Node {
+            position_type: PositionType::Absolute,
+            top: percent(30.0),
+            width: percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: px(8.0),
+            ..default()
+        },
+        children![
+            (
+                Text::new("Baba Yaga has caught your scent!"),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor::from(ANNOUNCEMENT_COLOR),
+            ),
+            (
+                Text::new("Find a portal before she finds you"),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+            ),
+        ],
+    ));
+}
+
+/// Baba Yaga arrives at the zone's edge, on the border tile farthest from the player.
+/// Border tiles are the outermost walkable tiles of each row and column
+fn arrival_tile(map_layout: &MapLayout, from: IVec2) -> Option<IVec2> {
+    let tiles = &map_layout.tiles;
+    let height = tiles.first().map_or(0, Vec::len);
+    let walkable = |x: usize, y: usize| {
+        tiles
+            .get(x)
+            .and_then(|column| column.get(y))
+            .is_some_and(|tile| tile.is_walkable())
+    };
+
+    let column_ends = (0..tiles.len()).flat_map(|x| {
+        outermost((0..height).filter(move |&y| walkable(x, y)))
+            .map(move |y| IVec2::new(x as i32, y as i32))
+    });
+    let row_ends = (0..height).flat_map(|y| {
+        outermost((0..tiles.len()).filter(move |&x| walkable(x, y)))
+            .map(move |x| IVec2::new(x as i32, y as i32))
+    });
+
+    column_ends
+        .chain(row_ends)
+        .max_by_key(|tile| tile.distance_squared(from))
+}
+
+/// First and last of `indices`
+fn outermost(mut indices: impl Iterator<Item = usize>) -> impl Iterator<Item = usize> {
+    let first = indices.next();
+    first.into_iter().chain(indices.last())
+}
+
+/// Breadth first search out from the player, every tile remembers which neighbour leads back toward them
+fn update_hunt_path(
+    time: Res<Time>,
+    mut hunt_path: ResMut<HuntPath>,
+    clock: Res<BabaYagaClock>,
+    map_layout: Res<MapLayout>,
+    world_config: Res<WorldSpaceConfig>,
+    player_transform: Single<&Transform, With<Player>>,
+) {
+    if !clock.arrived || !hunt_path.refresh.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let tiles = &map_layout.tiles;
+    let mut next_step = vec![vec![None; tiles.first().map_or(0, Vec::len)]; tiles.len()];
+
+    let walkable = |tile: IVec2| {
+        usize::try_from(tile.x)
+            .ok()
+            .zip(usize::try_from(tile.y).ok())
+            .and_then(|(x, y)| tiles.get(x)?.get(y))
+            .is_some_and(|tile_type| tile_type.is_walkable())
+    };
+
+    let player_tile =
+        world_config.world_to_tile(map_layout.size, player_transform.translation.xy());
+    if !walkable(player_tile) {
+        return;
+    }
+
+    next_step[player_tile.x as usize][player_tile.y as usize] = Some(player_tile);
+    let mut frontier = VecDeque::from([player_tile]);
+
+    while let Some(tile) = frontier.pop_front() {
+        for neighbour in [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y].map(|step| tile + step) {
+            if walkable(neighbour)
+                && next_step[neighbour.x as usize][neighbour.y as usize].is_none()
+            {
+                next_step[neighbour.x as usize][neighbour.y as usize] = Some(tile);
+                frontier.push_back(neighbour);
+            }
+        }
+    }
+
+    hunt_path.next_step = next_step;
+}
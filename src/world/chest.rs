@@ -81,6 +81,7 @@ fn chest(
 fn on_interaction_open_chest(
     chest_opened: On<PlayerInteraction>,
     chest_transforms: Query<&Transform, With<Chest>>,
+    player: Single<Entity, With<Player>>,
     mut commands: Commands,
 ) {
     let chest_entity = chest_opened.entity;
@@ -97,6 +98,12 @@ fn on_interaction_open_chest(
         .despawn();
 
     if let Ok(chest_transform) = chest_transforms.get(chest_entity) {
+        commands.trigger(Noise::new(
+            chest_transform.translation.truncate(),
+            Noise::CHEST_OPEN,
+            *player,
+        ));
+
         commands.trigger(GoldDrop {
             amount: 999,
             location: chest_transform.translation.truncate(),
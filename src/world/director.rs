@@ -0,0 +1,272 @@
+use std::{collections::VecDeque, io::BufReader};
+
+use bevy::{prelude::*, scene::ron::de::from_reader};
+use rand::{Rng, rng, seq::SliceRandom};
+use serde::Deserialize;
+
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Startup, setup_spawn_director)
+        .add_systems(
+            Update,
+            (update_intensity, send_reinforcements)
+                .chain()
+                .in_set(InGameSystems::Simulation),
+        )
+        .add_systems(OnEnter(AppState::CreateHub), reset_spawn_director)
+        .add_observer(on_player_damaged)
+        .add_observer(on_enemy_killed)
+        .add_observer(on_cleanup_zone);
+}
+
+/// Kills older than this no longer count toward the kill rate
+const KILL_WINDOW_SECS: f32 = 60.0;
+/// How quickly the health trend reacts to changes, higher is twitchier
+const HEALTH_TREND_SMOOTHING: f32 = 2.0;
+
+/// Tuning for the spawn director, loaded from `config/director.ron`
+#[derive(Deserialize, Debug)]
+pub struct DirectorConfig {
+    target_intensity: (f32, f32),
+    initial_spawn_fraction: f32,
+    health_weight: f32,
+    health_trend_weight: f32,
+    recent_damage_weight: f32,
+    kill_rate_weight: f32,
+    calm_after_damage: f32,
+    max_health_loss_rate: f32,
+    max_kills_per_minute: f32,
+    reinforcement_min_distance: f32,
+    reinforcement_interval: f32,
+    reinforcements_per_wave: usize,
+}
+
+/// Watches how the player is doing and paces enemy spawns to keep a zone's intensity within a target band.
+/// Some enemy markers are held back when a zone spawns and used for reinforcements if things get too quiet
+#[derive(Resource)]
+pub struct SpawnDirector {
+    config: DirectorConfig,
+    /// 0.0 is a stroll in the park, 1.0 is the player about to go down
+    intensity: f32,
+    /// Enemy markers not filled yet, in world space
+    held_back: Vec<Vec2>,
+    /// Elapsed time of each recent kill
+    kills: VecDeque<f32>,
+    last_damage: Option<f32>,
+    /// Smoothed change in player health fraction per second, negative while losing health
+    health_trend: f32,
+    previous_health: Option<f32>,
+    reinforcement_timer: Timer,
+}
+
+impl SpawnDirector {
+    pub fn intensity(&self) -> f32 {
+        self.intensity
+    }
+
+    /// Splits a new zone's enemy markers into those spawned right away and those held back for later.
+    /// A hurt player gets a lighter start
+    pub fn plan_zone(&mut self, mut positions: Vec<Vec2>, player_health: &Health) -> Vec<Vec2> {
+        positions.shuffle(&mut rng());
+
+        let spawn_fraction =
+            self.config.initial_spawn_fraction * player_health.fraction().clamp(0.5, 1.0);
+        let spawn_count = (positions.len() as f32 * spawn_fraction).ceil() as usize;
+
+        self.reset();
+        self.held_back = positions.split_off(spawn_count.min(positions.len()));
+
+        positions
+    }
+
+    /// Forgets everything about the current zone, so nothing is sent into a zone that no longer exists
+    fn reset(&mut self) {
+        self.intensity = 0.0;
+        self.held_back.clear();
+        self.kills.clear();
+        self.last_damage = None;
+        self.health_trend = 0.0;
+        self.previous_health = None;
+        self.reinforcement_timer.reset();
+    }
+
+    fn calculate_intensity(&self, health_fraction: f32, now: f32) -> f32 {
+        let config = &self.config;
+
+        let health_pressure = 1.0 - health_fraction;
+        let trend_pressure = (-self.health_trend / config.max_health_loss_rate).clamp(0.0, 1.0);
+        let damage_pressure = self.last_damage.map_or(0.0, |last_damage| {
+            1.0 - ((now - last_damage) / config.calm_after_damage).clamp(0.0, 1.0)
+        });
+        let kill_pressure = (self.kills.len() as f32 / config.max_kills_per_minute).clamp(0.0, 1.0);
+
+        (health_pressure * config.health_weight
+            + trend_pressure * config.health_trend_weight
+            + damage_pressure * config.recent_damage_weight
+            + kill_pressure * config.kill_rate_weight)
+            .clamp(0.0, 1.0)
+    }
+}
+
+fn update_intensity(
+    time: Res<Time>,
+    mut director: ResMut<SpawnDirector>,
+    player_health: Single<&Health, With<Player>>,
+) {
+    let now = time.elapsed_secs();
+    let health_fraction = player_health.fraction();
+
+    if time.delta_secs() > 0.0 {
+        let change_rate = director.previous_health.map_or(0.0, |previous| {
+            (health_fraction - previous) / time.delta_secs()
+        });
+        let smoothing = (HEALTH_TREND_SMOOTHING * time.delta_secs()).min(1.0);
+        director.health_trend += (change_rate - director.health_trend) * smoothing;
+    }
+    director.previous_health = Some(health_fraction);
+
+    while director
+        .kills
+        .front()
+        .is_some_and(|kill| now - kill > KILL_WINDOW_SECS)
+    {
+        director.kills.pop_front();
+    }
+
+    director.intensity = director.calculate_intensity(health_fraction, now);
+}
+
+fn send_reinforcements(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut director: ResMut<SpawnDirector>,
+    zone_difficulty: Res<ZoneDifficulty>,
+    enemy_registry: Res<EnemyRegistry>,
+    player_transform: Single<&Transform, With<Player>>,
+) {
+    let (low, high) = director.config.target_intensity;
+
+    // Too hectic, reinforcements wait a full interval once things calm down
+    if director.intensity >= high {
+        director.reinforcement_timer.reset();
+        return;
+    }
+
+    // Within the band (or nothing left to send), leave the zone as it is
+    if director.held_back.is_empty() || director.intensity >= low {
+        return;
+    }
+
+    if !director
+        .reinforcement_timer
+        .tick(time.delta())
+        .just_finished()
+    {
+        return;
+    }
+
+    let player_position = player_transform.translation.xy();
+    let min_distance = director.config.reinforcement_min_distance;
+    let enemy_types: Vec<&EnemyType> = enemy_registry.enemy_types().collect();
+    if enemy_types.is_empty() {
+        return;
+    }
+
+    let mut rng = rng();
+    let mut spawn_data = Vec::new();
+    let mut index = 0;
+    while index < director.held_back.len()
+        && spawn_data.len() < director.config.reinforcements_per_wave
+    {
+        if director.held_back[index].distance(player_position) < min_distance {
+            index += 1;
+            continue;
+        }
+
+        spawn_data.push(EnemySpawnData {
+            position: director.held_back.swap_remove(index),
+            enemy_type: enemy_types[rng.random_range(0..enemy_types.len())].clone(),
+            elite: Elite::roll(&zone_difficulty),
+            spawned_by: None,
+        });
+    }
+
+    if !spawn_data.is_empty() {
+        debug!(
+            "Director sending {} reinforcements at intensity {:.2}",
+            spawn_data.len(),
+            director.intensity
+        );
+        commands.trigger(SpawnEnemies(spawn_data));
+    }
+}
+
+fn on_player_damaged(
+    damage_dealt: On<DamageDealt>,
+    time: Res<Time>,
+    mut director: ResMut<SpawnDirector>,
+    player_query: Query<(), With<Player>>,
+) {
+    if player_query.contains(damage_dealt.entity) {
+        director.last_damage = Some(time.elapsed_secs());
+    }
+}
+
+fn on_enemy_killed(
+    defeated: On<Defeated>,
+    time: Res<Time>,
+    mut director: ResMut<SpawnDirector>,
+    enemy_query: Query<(), (With<Enemy>, Without<Minion>)>,
+) {
+    if enemy_query.contains(defeated.entity) {
+        director.kills.push_back(time.elapsed_secs());
+    }
+}
+
+fn on_cleanup_zone(_: On<CleanupZone>, mut director: ResMut<SpawnDirector>) {
+    director.reset();
+}
+
+fn reset_spawn_director(mut director: ResMut<SpawnDirector>) {
+    director.reset();
+}
+
+fn setup_spawn_director(mut commands: Commands) {
+    let config = load_director_data();
+    let reinforcement_timer =
+        Timer::from_seconds(config.reinforcement_interval, TimerMode::Repeating);
+
+    commands.insert_resource(SpawnDirector {
+        config,
+        intensity: 0.0,
+        held_back: Vec::new(),
+        kills: VecDeque::new(),
+        last_damage: None,
+        health_trend: 0.0,
+        previous_health: None,
+        reinforcement_timer,
+    });
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs::File;
+
+#[cfg(not(target_arch = "wasm32"))]
+fn fetch_director_data() -> File {
+    File::open("assets/config/director.ron").expect("Failed to open RON file")
+}
+
+#[cfg(target_arch = "wasm32")]
+fn fetch_director_data() -> &'static [u8] {
+    include_bytes!("../../assets/config/director.ron")
+}
+
+fn load_director_data() -> DirectorConfig {
+    let reader = BufReader::new(fetch_director_data());
+
+    from_reader::<_, DirectorConfig>(reader).unwrap_or_else(|e| {
+        error!("Failed to parse RON file: {:?}", e);
+        panic!("RON parsing error");
+    })
+}
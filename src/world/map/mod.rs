@@ -51,6 +51,9 @@ pub enum MarkerType {
     NPCSpawns,
     PlayerSpawns,
     LevelExits,
+    SpawnerSpawns,
+    /// Tiles that get blocked off while a room-locking encounter is in progress
+    RoomGates,
 }
 
 #[derive(Clone, Default, Debug)]
@@ -129,6 +132,20 @@ impl WorldSpaceConfig {
             )
             + offset
     }
+
+    /// Inverse of `tile_to_world`, gives the tile a world position falls in
+    pub fn world_to_tile(&self, map_size_in_tiles: TilemapSize, world_pos: Vec2) -> IVec2 {
+        let origin_tile = self.tile_to_world(map_size_in_tiles, IVec2::ZERO);
+        let tile_size = Vec2::new(self.tile_size.x, self.tile_size.y);
+        ((world_pos - origin_tile) / tile_size).round().as_ivec2()
+    }
+}
+
+impl TileType {
+    /// Whether characters can walk over the tile
+    pub fn is_walkable(self) -> bool {
+        !matches!(self, TileType::Wall | TileType::Water | TileType::DeadZone)
+    }
 }
 
 #[derive(Resource, Default, Clone)]
@@ -31,13 +31,26 @@ impl Prefab for Temple {
     fn get_markers(&self, bounds: &Rect) -> HashMap<MarkerType, Vec<Vec2>> {
         let mut markers = HashMap::new();
 
-        let chest_pos = Vec2::new(
+        let center = Vec2::new(
             bounds.min.x + TEMPLE_WIDTH as f32 / 2.0,
             bounds.min.y + TEMPLE_HEIGHT as f32 / 2.0,
         );
 
+        // Summoning circle sits in the middle of the temple so each wave's ring of enemies lands inside the walls.
+        // The entrance locks until its waves are cleared
+        markers.insert(MarkerType::SpawnerSpawns, vec![center]);
+
+        // Chest waits at the back of the temple, behind the circle
+        let chest_pos = center - Vec2::new(0.0, 2.0);
         markers.insert(MarkerType::ChestSpawns, vec![chest_pos]);
 
+        let entrance_start = bounds.min.x + ((TEMPLE_WIDTH - ENTRANCE_WIDTH) / 2) as f32;
+        let entrance_y = bounds.min.y + (TEMPLE_HEIGHT - 1) as f32;
+        let gate_positions = (0..ENTRANCE_WIDTH)
+            .map(|offset| Vec2::new(entrance_start + offset as f32, entrance_y))
+            .collect();
+        markers.insert(MarkerType::RoomGates, gate_positions);
+
         markers
     }
 }
@@ -24,6 +24,9 @@ pub(super) fn plugin(app: &mut App) {
             .chain(),
     );
 
+    app.init_resource::<ZoneDifficulty>()
+        .add_systems(OnEnter(AppState::CreateHub), reset_zone_difficulty);
+
     app.add_observer(despawn_all::<CleanupZone, TilemapId>)
         .add_observer(despawn_all::<CleanupZone, Wall>)
         .add_observer(despawn_all::<CleanupZone, Water>);
@@ -32,6 +35,16 @@ pub(super) fn plugin(app: &mut App) {
 #[derive(Event)]
 pub struct CleanupZone;
 
+/// How many zones deep the player has gone since leaving the hub. Deeper zones are more dangerous
+#[derive(Resource, Default)]
+pub struct ZoneDifficulty {
+    pub depth: u32,
+}
+
+fn reset_zone_difficulty(mut zone_difficulty: ResMut<ZoneDifficulty>) {
+    zone_difficulty.depth = 0;
+}
+
 fn transition_to_playing(mut game_state: ResMut<NextState<AppState>>) {
     game_state.set(AppState::Playing);
 }
@@ -114,8 +127,13 @@ fn spawn_zone_entities(
     sprites: Res<SpriteAssets>,
     map_layout: Res<MapLayout>,
     world_config: Res<WorldSpaceConfig>,
-    player_query: Single<&mut Transform, With<Player>>,
+    zone_difficulty: Res<ZoneDifficulty>,
+    enemy_registry: Res<EnemyRegistry>,
+    mut spawn_director: ResMut<SpawnDirector>,
+    player_query: Single<(&mut Transform, &Health), With<Player>>,
 ) {
+    let (mut player_transform, player_health) = player_query.into_inner();
+
     //TODO: Markers should all store an associated type
     //So maps can have a set of enemy types that they create markers for
     //and chest types, and NPC types
@@ -128,17 +146,24 @@ fn spawn_zone_entities(
         }
     }
 
-    if let Some(enemy_positions) = map_layout.markers.get_markers(MarkerType::EnemySpawns) {
-        let spawn_positions =
+    let enemy_types: Vec<&EnemyType> = enemy_registry.enemy_types().collect();
+    // A registry of nothing but bosses leaves no regular enemies to place on the markers
+    if let Some(enemy_positions) = map_layout.markers.get_markers(MarkerType::EnemySpawns)
+        && !enemy_types.is_empty()
+    {
+        let marker_positions =
             convert_tiles_to_world_positions(enemy_positions, &world_config, &map_layout);
+        // The director holds some markers back to send reinforcements later
+        let spawn_positions = spawn_director.plan_zone(marker_positions, player_health);
         let mut rng = rng();
-        let enemy_types = [EnemyType::FireMage, EnemyType::IceMage, EnemyType::Warrior];
 
         let enemy_spawn_data_list = spawn_positions
             .into_iter()
             .map(|pos| EnemySpawnData {
                 position: pos,
-                enemy_type: enemy_types[rng.random_range(0..3)].clone(),
+                enemy_type: enemy_types[rng.random_range(0..enemy_types.len())].clone(),
+                elite: Elite::roll(&zone_difficulty),
+                spawned_by: None,
             })
             .collect();
 
@@ -153,6 +178,19 @@ fn spawn_zone_entities(
         commands.trigger(SpawnChestsEvent(spawn_positions));
     }
 
+    // Spawn enemy spawners and the gates that lock rooms while they are active
+    if let Some(spawner_positions) = map_layout.markers.get_markers(MarkerType::SpawnerSpawns) {
+        let spawn_positions =
+            convert_tiles_to_world_positions(spawner_positions, &world_config, &map_layout);
+        commands.trigger(SpawnSpawnersEvent(spawn_positions));
+    }
+
+    if let Some(gate_positions) = map_layout.markers.get_markers(MarkerType::RoomGates) {
+        let spawn_positions =
+            convert_tiles_to_world_positions(gate_positions, &world_config, &map_layout);
+        commands.trigger(SpawnRoomGatesEvent(spawn_positions));
+    }
+
     // Spawn NPCs
     if let Some(npc_positions) = map_layout.markers.get_markers(MarkerType::NPCSpawns) {
         let spawn_positions =
@@ -167,8 +205,6 @@ fn spawn_zone_entities(
             let player_spawn_position =
                 world_config.tile_to_world(map_layout.size, spawn_position.as_ivec2());
 
-            let mut player_transform = player_query.into_inner();
-
             info!("moving player");
 
             player_transform.translation =
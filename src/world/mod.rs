@@ -1,17 +1,31 @@
+mod baba_yaga;
 mod chest;
+mod director;
 mod gold;
 mod map;
 mod portal;
+mod spawner;
 
 use bevy::prelude::*;
 
 pub mod prelude {
+    pub use super::baba_yaga::{BabaYagaClock, HuntPath};
     pub use super::chest::*;
+    pub use super::director::SpawnDirector;
     pub use super::gold::*;
     pub use super::map::prelude::*;
     pub use super::portal::*;
+    pub use super::spawner::*;
 }
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_plugins((chest::plugin, gold::plugin, portal::plugin, map::plugin));
+    app.add_plugins((
+        baba_yaga::plugin,
+        chest::plugin,
+        director::plugin,
+        gold::plugin,
+        portal::plugin,
+        spawner::plugin,
+        map::plugin,
+    ));
 }
@@ -41,11 +41,13 @@ fn handle_portal_collisions(
     portal_query: Query<&CollidingEntities, With<Portal>>,
     player_collider: Single<Entity, With<PlayerInteractionRadius>>,
     mut game_state: ResMut<NextState<AppState>>,
+    mut zone_difficulty: ResMut<ZoneDifficulty>,
 ) {
     for portal_colliding_entities in portal_query.iter() {
         for &colliding_entity in portal_colliding_entities.iter() {
             if colliding_entity == *player_collider {
                 commands.insert_resource(instance.generate_map_layout().unwrap());
+                zone_difficulty.depth += 1;
                 game_state.set(AppState::Transition);
             }
         }
@@ -0,0 +1,300 @@
+use avian2d::prelude::*;
+use bevy::{color::palettes::tailwind::PURPLE_500, prelude::*, ui_widgets::observe};
+use bevy_lit::prelude::PointLight2d;
+use rand::{Rng, rng, seq::IndexedRandom};
+
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            activate_nearby_spawners,
+            tick_spawner_waves,
+            update_room_gates,
+        )
+            .chain()
+            .in_set(InGameSystems::Simulation),
+    )
+    .add_observer(on_spawn_spawners)
+    .add_observer(on_spawn_room_gates)
+    .add_observer(on_spawn_wave);
+
+    app.add_observer(despawn_all::<CleanupZone, Spawner>)
+        .add_observer(despawn_all::<CleanupZone, RoomGate>);
+}
+
+/// How far from the spawner new enemies appear
+const SPAWN_RING_RADIUS: f32 = 48.0;
+const SPAWNER_SIZE: f32 = 40.0;
+
+#[derive(Debug, Event)]
+pub struct SpawnSpawnersEvent(pub Vec<Vec2>);
+
+#[derive(Debug, Event)]
+pub struct SpawnRoomGatesEvent(pub Vec<Vec2>);
+
+/// Sends out a wave from the spawner right away, as long as it has waves left
+#[derive(EntityEvent)]
+pub struct SpawnWave {
+    pub entity: Entity,
+}
+
+/// Summoning circles, nests, etc... Anything that keeps bringing enemies into the zone in waves until
+/// it runs out or gets destroyed
+#[derive(Component)]
+#[require(RigidBody::Static, Health::new(60.0), Transform)]
+pub struct Spawner {
+    enemy_types: Vec<EnemyType>,
+    wave_size: usize,
+    waves_remaining: u32,
+    /// No new wave is sent while this many enemies from the spawner are still alive
+    max_alive: usize,
+    /// `None` for spawners that only send waves when sent a `SpawnWave` event
+    wave_timer: Option<Timer>,
+    /// Inactive spawners don't tick their wave timer
+    active: bool,
+}
+
+impl Spawner {
+    pub fn builder(enemy_types: Vec<EnemyType>) -> Self {
+        Self {
+            enemy_types,
+            wave_size: 3,
+            waves_remaining: 3,
+            max_alive: 6,
+            wave_timer: None,
+            active: true,
+        }
+    }
+
+    pub fn wave_size(mut self, wave_size: usize) -> Self {
+        self.wave_size = wave_size;
+        self
+    }
+
+    pub fn waves(mut self, waves: u32) -> Self {
+        self.waves_remaining = waves;
+        self
+    }
+
+    pub fn max_alive(mut self, max_alive: usize) -> Self {
+        self.max_alive = max_alive;
+        self
+    }
+
+    pub fn wave_interval(mut self, seconds: f32) -> Self {
+        self.wave_timer = Some(Timer::from_seconds(seconds, TimerMode::Repeating));
+        self
+    }
+
+    /// Spawner stays dormant until the player comes within `ActivationRadius`
+    pub fn dormant(mut self) -> Self {
+        self.active = false;
+        self
+    }
+}
+
+/// Activates a dormant spawner once the player gets this close, sending its first wave right away
+#[derive(Component)]
+pub struct ActivationRadius(pub f32);
+
+/// While a spawner with this is active and has waves or enemies left, all `RoomGate`s stay closed
+#[derive(Component)]
+pub struct LocksRoom;
+
+/// Blocks off a room while an encounter is going on
+#[derive(Component)]
+#[require(RigidBody::Static, Transform)]
+pub struct RoomGate;
+
+fn summoning_circle(
+    position: Vec2,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+) -> impl Bundle {
+    (
+        Name::new("Summoning Circle"),
+        Spawner::builder(vec!["Warrior".into(), "FireMage".into(), "Bat".into()])
+            .waves(3)
+            .wave_size(3)
+            .max_alive(5)
+            .wave_interval(8.0)
+            .dormant(),
+        ActivationRadius(80.0),
+        LocksRoom,
+        Transform::from_translation(position.extend(ZLayer::OnGround.z())),
+        Mesh2d(meshes.add(Annulus::new(SPAWNER_SIZE / 2.0 - 4.0, SPAWNER_SIZE / 2.0))),
+        MeshMaterial2d(materials.add(Color::from(PURPLE_500))),
+        PointLight2d {
+            color: Color::from(PURPLE_500),
+            intensity: 1.5,
+            falloff: 5.0,
+            outer_radius: 100.0,
+            ..default()
+        },
+        children![hurtbox(Vec2::splat(SPAWNER_SIZE), Faction::Enemy)],
+        observe(on_spawner_destroyed),
+    )
+}
+
+fn room_gate(position: Vec2, world_config: &WorldSpaceConfig) -> impl Bundle {
+    (
+        Name::new("Room Gate"),
+        RoomGate,
+        Sprite::from_color(
+            Color::from(PURPLE_500).with_alpha(0.6),
+            Vec2::new(world_config.tile_size.x, world_config.tile_size.y),
+        ),
+        Transform::from_translation(position.extend(ZLayer::OnGround.z())),
+        Collider::rectangle(world_config.tile_size.x, world_config.tile_size.y),
+        // Gates start open, encounters close them
+        CollisionLayers::NONE,
+        Visibility::Hidden,
+    )
+}
+
+fn on_spawn_spawners(
+    spawn_spawners: On<SpawnSpawnersEvent>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for &position in &spawn_spawners.0 {
+        commands.spawn(summoning_circle(position, &mut meshes, &mut materials));
+    }
+}
+
+fn on_spawn_room_gates(
+    spawn_gates: On<SpawnRoomGatesEvent>,
+    mut commands: Commands,
+    world_config: Res<WorldSpaceConfig>,
+) {
+    for &position in &spawn_gates.0 {
+        commands.spawn(room_gate(position, &world_config));
+    }
+}
+
+fn activate_nearby_spawners(
+    mut commands: Commands,
+    mut spawner_query: Query<(Entity, &mut Spawner, &ActivationRadius, &Transform)>,
+    player: Single<&Transform, With<Player>>,
+) {
+    let player_position = player.translation.xy();
+
+    for (entity, mut spawner, activation_radius, transform) in &mut spawner_query {
+        if !spawner.active
+            && transform.translation.xy().distance(player_position) <= activation_radius.0
+        {
+            spawner.active = true;
+            commands.trigger(SpawnWave { entity });
+        }
+    }
+}
+
+fn tick_spawner_waves(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut spawner_query: Query<(Entity, &mut Spawner)>,
+) {
+    for (entity, mut spawner) in &mut spawner_query {
+        if !spawner.active || spawner.waves_remaining == 0 {
+            continue;
+        }
+
+        if let Some(wave_timer) = spawner.wave_timer.as_mut()
+            && wave_timer.tick(time.delta()).just_finished()
+        {
+            commands.trigger(SpawnWave { entity });
+        }
+    }
+}
+
+fn on_spawn_wave(
+    spawn_wave: On<SpawnWave>,
+    mut commands: Commands,
+    zone_difficulty: Res<ZoneDifficulty>,
+    mut spawner_query: Query<(&mut Spawner, &Transform, Option<&SpawnedEnemies>)>,
+    health_query: Query<&Health>,
+) {
+    let Ok((mut spawner, transform, spawned_enemies)) = spawner_query.get_mut(spawn_wave.entity)
+    else {
+        return;
+    };
+
+    if spawner.waves_remaining == 0 {
+        return;
+    }
+
+    let alive = count_alive(spawned_enemies, &health_query);
+    let wave_size = spawner
+        .wave_size
+        .min(spawner.max_alive.saturating_sub(alive));
+    if wave_size == 0 {
+        return;
+    }
+
+    let mut rng = rng();
+    let spawner_position = transform.translation.xy();
+    let spawn_data = (0..wave_size)
+        .filter_map(|_| {
+            let enemy_type = spawner.enemy_types.choose(&mut rng)?.clone();
+            let angle = rng.random_range(0.0..std::f32::consts::TAU);
+            Some(EnemySpawnData {
+                position: spawner_position + Vec2::from_angle(angle) * SPAWN_RING_RADIUS,
+                enemy_type,
+                elite: Elite::roll(&zone_difficulty),
+                spawned_by: Some(spawn_wave.entity),
+            })
+        })
+        .collect();
+
+    spawner.waves_remaining -= 1;
+    commands.trigger(SpawnEnemies(spawn_data));
+}
+
+fn count_alive(spawned_enemies: Option<&SpawnedEnemies>, health_query: &Query<&Health>) -> usize {
+    spawned_enemies
+        .into_iter()
+        .flat_map(SpawnedEnemies::iter)
+        .filter(|&enemy| {
+            health_query
+                .get(enemy)
+                .is_ok_and(|health| !health.is_dead())
+        })
+        .count()
+}
+
+/// Gates close once a room-locking encounter starts and only open back up after every wave is cleared
+fn update_room_gates(
+    spawner_query: Query<(&Spawner, Option<&SpawnedEnemies>), With<LocksRoom>>,
+    health_query: Query<&Health>,
+    mut gate_query: Query<(&mut CollisionLayers, &mut Visibility), With<RoomGate>>,
+) {
+    let encounter_in_progress = spawner_query.iter().any(|(spawner, spawned_enemies)| {
+        spawner.active
+            && (spawner.waves_remaining > 0 || count_alive(spawned_enemies, &health_query) > 0)
+    });
+
+    let (gate_layers, gate_visibility) = if encounter_in_progress {
+        (
+            CollisionLayers::new(
+                GameCollisionLayer::HighObstacle,
+                GameCollisionLayer::HIGH_OBSTACLE_FILTERS,
+            ),
+            Visibility::Visible,
+        )
+    } else {
+        (CollisionLayers::NONE, Visibility::Hidden)
+    };
+
+    // Only write on change so physics doesn't see new layers every frame
+    for (mut layers, mut visibility) in &mut gate_query {
+        layers.set_if_neq(gate_layers);
+        visibility.set_if_neq(gate_visibility);
+    }
+}
+
+fn on_spawner_destroyed(destroyed: On<Defeated>, mut commands: Commands) {
+    commands.entity(destroyed.entity).despawn();
+}